@@ -1,4 +1,6 @@
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum BlackboardValue {
@@ -7,6 +9,8 @@ pub enum BlackboardValue {
     Bool(bool),
     Entity(u32),
     Vec2(i32, i32),
+    /// Full-precision `f32`, stored as its raw bits so the enum stays `Eq`.
+    Float(u32),
 }
 
 impl BlackboardValue {
@@ -14,6 +18,11 @@ impl BlackboardValue {
         BlackboardValue::Fixed((v * 1000.0) as i32)
     }
 
+    /// Stores `v` without the 3-decimal quantization of [`from_f32`](Self::from_f32).
+    pub fn real_float(v: f32) -> Self {
+        BlackboardValue::Float(v.to_bits())
+    }
+
     pub fn as_int(self) -> Option<i32> {
         match self {
             BlackboardValue::Int(v) => Some(v),
@@ -28,6 +37,13 @@ impl BlackboardValue {
         }
     }
 
+    pub fn as_real_float(self) -> Option<f32> {
+        match self {
+            BlackboardValue::Float(bits) => Some(f32::from_bits(bits)),
+            _ => None,
+        }
+    }
+
     pub fn as_bool(self) -> Option<bool> {
         match self {
             BlackboardValue::Bool(v) => Some(v),
@@ -49,6 +65,54 @@ impl BlackboardValue {
         }
     }
 
+    /// Euclidean length of a `Vec2`; `None` for other variants.
+    pub fn magnitude(self) -> Option<f32> {
+        self.as_vec2().map(|(x, y)| {
+            let (xf, yf) = (x as f32, y as f32);
+            libm::sqrtf(xf * xf + yf * yf)
+        })
+    }
+
+    /// Manhattan length of a `Vec2`, saturating on overflow; `None` for other variants.
+    pub fn manhattan(self) -> Option<i32> {
+        self.as_vec2()
+            .map(|(x, y)| x.saturating_abs().saturating_add(y.saturating_abs()))
+    }
+
+    /// Euclidean distance from a `Vec2` to `other`; `None` for other variants.
+    pub fn distance_to(self, other: (i32, i32)) -> Option<f32> {
+        self.as_vec2().map(|(x, y)| {
+            let dx = x as f32 - other.0 as f32;
+            let dy = y as f32 - other.1 as f32;
+            libm::sqrtf(dx * dx + dy * dy)
+        })
+    }
+
+    /// Reads any scalar variant as an int: fixed values truncate toward zero, bools map to
+    /// 0/1 and entities saturate at `i32::MAX`. Returns `None` for `Vec2`.
+    pub fn coerce_int(self) -> Option<i32> {
+        match self {
+            BlackboardValue::Int(v) => Some(v),
+            BlackboardValue::Fixed(v) => Some(v / 1000),
+            BlackboardValue::Bool(v) => Some(v as i32),
+            BlackboardValue::Entity(v) => Some(v.min(i32::MAX as u32) as i32),
+            BlackboardValue::Float(bits) => Some(f32::from_bits(bits) as i32),
+            BlackboardValue::Vec2(..) => None,
+        }
+    }
+
+    /// Reads any scalar variant as a float, mapping bools to 0.0/1.0. Returns `None` for `Vec2`.
+    pub fn coerce_float(self) -> Option<f32> {
+        match self {
+            BlackboardValue::Int(v) => Some(v as f32),
+            BlackboardValue::Fixed(v) => Some((v as f32) / 1000.0),
+            BlackboardValue::Bool(v) => Some(if v { 1.0 } else { 0.0 }),
+            BlackboardValue::Entity(v) => Some(v as f32),
+            BlackboardValue::Float(bits) => Some(f32::from_bits(bits)),
+            BlackboardValue::Vec2(..) => None,
+        }
+    }
+
     pub fn is_truthy(self) -> bool {
         match self {
             BlackboardValue::Int(v) => v != 0,
@@ -56,6 +120,7 @@ impl BlackboardValue {
             BlackboardValue::Bool(v) => v,
             BlackboardValue::Entity(v) => v != 0,
             BlackboardValue::Vec2(x, y) => x != 0 || y != 0,
+            BlackboardValue::Float(bits) => f32::from_bits(bits) != 0.0,
         }
     }
 
@@ -76,6 +141,42 @@ impl BlackboardValue {
                 let yf = y as f32;
                 libm::sqrtf(xf * xf + yf * yf)
             }
+            BlackboardValue::Float(bits) => f32::from_bits(bits),
+        }
+    }
+
+    /// Tests `self op value`, exactly for ints and via the value's score otherwise.
+    pub fn compare(self, op: CompareOp, value: i32) -> bool {
+        match self.as_int() {
+            Some(v) => op.matches(v.cmp(&value)),
+            None => self
+                .to_score_f32()
+                .partial_cmp(&(value as f32))
+                .is_some_and(|ordering| op.matches(ordering)),
+        }
+    }
+}
+
+/// Relation tested by `BehaviorNode::Compare`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    pub fn matches(self, ordering: Ordering) -> bool {
+        match self {
+            CompareOp::Eq => ordering == Ordering::Equal,
+            CompareOp::Ne => ordering != Ordering::Equal,
+            CompareOp::Lt => ordering == Ordering::Less,
+            CompareOp::Le => ordering != Ordering::Greater,
+            CompareOp::Gt => ordering == Ordering::Greater,
+            CompareOp::Ge => ordering != Ordering::Less,
         }
     }
 }
@@ -83,6 +184,11 @@ impl BlackboardValue {
 #[derive(Clone, Debug, Default)]
 pub struct Blackboard {
     entries: BTreeMap<u32, BlackboardValue>,
+    /// Entity lists live beside `entries` (own key space) so `BlackboardValue` stays `Copy`.
+    entity_lists: BTreeMap<u32, Vec<u32>>,
+    /// Keys written or dropped by `set`, `remove`, `clear` or `retain` since the last
+    /// `take_dirty`; `None` until [`track_dirty`](Blackboard::track_dirty) enables tracking.
+    dirty: Option<BTreeSet<u32>>,
 }
 
 impl Blackboard {
@@ -102,6 +208,10 @@ impl Blackboard {
         self.get(key).and_then(BlackboardValue::as_float)
     }
 
+    pub fn get_real_float(&self, key: u32) -> Option<f32> {
+        self.get(key).and_then(BlackboardValue::as_real_float)
+    }
+
     pub fn get_bool(&self, key: u32) -> Option<bool> {
         self.get(key).and_then(BlackboardValue::as_bool)
     }
@@ -114,8 +224,17 @@ impl Blackboard {
         self.get(key).and_then(BlackboardValue::as_vec2)
     }
 
+    /// Distance between the `Vec2` values at two keys, if both are present.
+    pub fn vec2_distance(&self, key_a: u32, key_b: u32) -> Option<f32> {
+        let b = self.get_vec2(key_b)?;
+        self.get(key_a)?.distance_to(b)
+    }
+
     pub fn set(&mut self, key: u32, value: BlackboardValue) {
         self.entries.insert(key, value);
+        if let Some(dirty) = &mut self.dirty {
+            dirty.insert(key);
+        }
     }
 
     pub fn set_int(&mut self, key: u32, value: i32) {
@@ -126,6 +245,24 @@ impl Blackboard {
         self.set(key, BlackboardValue::from_f32(value));
     }
 
+    /// Stores `value` at full `f32` precision; read it back with `get_real_float`.
+    pub fn set_real_float(&mut self, key: u32, value: f32) {
+        self.set(key, BlackboardValue::real_float(value));
+    }
+
+    /// Stores `value` clamped to the range between `min` and `max`; swapped bounds are
+    /// reordered rather than panicking.
+    pub fn set_int_clamped(&mut self, key: u32, value: i32, min: i32, max: i32) {
+        self.set_int(key, value.clamp(min.min(max), min.max(max)));
+    }
+
+    /// Float counterpart of [`set_int_clamped`](Self::set_int_clamped); a NaN bound leaves
+    /// that side unbounded instead of panicking.
+    pub fn set_float_clamped(&mut self, key: u32, value: f32, min: f32, max: f32) {
+        let (low, high) = if min > max { (max, min) } else { (min, max) };
+        self.set_float(key, value.max(low).min(high));
+    }
+
     pub fn set_bool(&mut self, key: u32, value: bool) {
         self.set(key, BlackboardValue::Bool(value));
     }
@@ -138,16 +275,104 @@ impl Blackboard {
         self.set(key, BlackboardValue::Vec2(x, y));
     }
 
+    /// Adds `delta` to the int at `key` (missing counts as 0) and returns the stored result.
+    /// Returns `None` and leaves the entry untouched if `key` holds a non-`Int` value.
+    pub fn add_int(&mut self, key: u32, delta: i32) -> Option<i32> {
+        let value = match self.get(key) {
+            None => delta,
+            Some(BlackboardValue::Int(current)) => current.saturating_add(delta),
+            Some(_) => return None,
+        };
+        self.set_int(key, value);
+        Some(value)
+    }
+
+    /// Adds `delta` to the fixed-point float at `key` (missing counts as 0.0) and returns the
+    /// stored result. Returns `None` and leaves the entry untouched if `key` holds any other
+    /// kind of value, including a full-precision float.
+    pub fn add_float(&mut self, key: u32, delta: f32) -> Option<f32> {
+        let current = match self.get(key) {
+            None => 0.0,
+            Some(value) => value.as_float()?,
+        };
+        let stored = BlackboardValue::from_f32(current + delta);
+        self.set(key, stored);
+        stored.as_float()
+    }
+
+    /// Flips the bool at `key` (missing counts as false) and returns the new value. Returns
+    /// `None` and leaves the entry untouched if `key` holds a non-`Bool` value.
+    pub fn toggle_bool(&mut self, key: u32) -> Option<bool> {
+        let value = match self.get(key) {
+            None => true,
+            Some(value) => !value.as_bool()?,
+        };
+        self.set_bool(key, value);
+        Some(value)
+    }
+
     pub fn has(&self, key: u32) -> bool {
         self.entries.contains_key(&key)
     }
 
+    pub fn set_entities(&mut self, key: u32, entities: &[u32]) {
+        self.entity_lists.insert(key, entities.to_vec());
+    }
+
+    pub fn get_entities(&self, key: u32) -> Option<&[u32]> {
+        self.entity_lists.get(&key).map(Vec::as_slice)
+    }
+
+    /// Appends `entity` to the list at `key` unless it is already there.
+    pub fn push_entity(&mut self, key: u32, entity: u32) {
+        let list = self.entity_lists.entry(key).or_default();
+        if !list.contains(&entity) {
+            list.push(entity);
+        }
+    }
+
+    /// Removes `entity` from the list at `key`, returning whether it was present.
+    pub fn remove_entity(&mut self, key: u32, entity: u32) -> bool {
+        match self.entity_lists.get_mut(&key) {
+            Some(list) => match list.iter().position(|e| *e == entity) {
+                Some(idx) => {
+                    list.remove(idx);
+                    true
+                }
+                None => false,
+            },
+            None => false,
+        }
+    }
+
+    pub fn clear_entities(&mut self, key: u32) -> Option<Vec<u32>> {
+        self.entity_lists.remove(&key)
+    }
+
     pub fn remove(&mut self, key: u32) -> Option<BlackboardValue> {
-        self.entries.remove(&key)
+        let removed = self.entries.remove(&key);
+        if let (Some(dirty), Some(_)) = (&mut self.dirty, removed) {
+            dirty.insert(key);
+        }
+        removed
+    }
+
+    /// Starts recording keys written by `set`/`remove`; tracking is off by default.
+    pub fn track_dirty(&mut self) {
+        self.dirty.get_or_insert_with(BTreeSet::new);
+    }
+
+    /// Drains the keys written since tracking began or the last call.
+    pub fn take_dirty(&mut self) -> BTreeSet<u32> {
+        self.dirty.as_mut().map(core::mem::take).unwrap_or_default()
     }
 
     pub fn clear(&mut self) {
+        if let Some(dirty) = &mut self.dirty {
+            dirty.extend(self.entries.keys().copied());
+        }
         self.entries.clear();
+        self.entity_lists.clear();
     }
 
     pub fn len(&self) -> usize {
@@ -157,11 +382,258 @@ impl Blackboard {
     pub fn is_empty(&self) -> bool {
         self.entries.is_empty()
     }
+
+    /// Iterates entries in ascending key order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, BlackboardValue)> + '_ {
+        self.entries.iter().map(|(k, v)| (*k, *v))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = u32> + '_ {
+        self.entries.keys().copied()
+    }
+
+    pub fn retain(&mut self, mut f: impl FnMut(u32, BlackboardValue) -> bool) {
+        let dirty = &mut self.dirty;
+        self.entries.retain(|k, v| {
+            let keep = f(*k, *v);
+            if let (Some(dirty), false) = (dirty.as_mut(), keep) {
+                dirty.insert(*k);
+            }
+            keep
+        });
+    }
+
+    /// Borrows the board through a view confined to `namespace`'s key range.
+    pub fn namespace(&mut self, namespace: KeyNamespace) -> NamespacedBlackboard<'_> {
+        NamespacedBlackboard {
+            board: self,
+            namespace,
+        }
+    }
+}
+
+/// Maps a subsystem's local keys `0..len` onto the reserved range `base..base + len` of the
+/// shared blackboard.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct KeyNamespace {
+    base: u32,
+    len: u32,
+}
+
+impl KeyNamespace {
+    pub const fn new(base: u32, len: u32) -> Self {
+        Self { base, len }
+    }
+
+    pub const fn base(self) -> u32 {
+        self.base
+    }
+
+    pub const fn len(self) -> u32 {
+        self.len
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.len == 0
+    }
+
+    /// The blackboard key for `local`, i.e. `base + local`, or `None` if `local` falls outside
+    /// the reserved range or past `u32::MAX`.
+    pub const fn key(self, local: u32) -> Option<u32> {
+        if local < self.len {
+            self.base.checked_add(local)
+        } else {
+            None
+        }
+    }
+}
+
+/// A [`Blackboard`] view that reads and writes only through a [`KeyNamespace`]; keys outside
+/// the namespace read as missing and aren't written.
+pub struct NamespacedBlackboard<'a> {
+    board: &'a mut Blackboard,
+    namespace: KeyNamespace,
+}
+
+impl NamespacedBlackboard<'_> {
+    pub fn namespace(&self) -> KeyNamespace {
+        self.namespace
+    }
+
+    pub fn get(&self, key: u32) -> Option<BlackboardValue> {
+        self.board.get(self.namespace.key(key)?)
+    }
+
+    pub fn get_int(&self, key: u32) -> Option<i32> {
+        self.board.get_int(self.namespace.key(key)?)
+    }
+
+    pub fn get_float(&self, key: u32) -> Option<f32> {
+        self.board.get_float(self.namespace.key(key)?)
+    }
+
+    pub fn get_bool(&self, key: u32) -> Option<bool> {
+        self.board.get_bool(self.namespace.key(key)?)
+    }
+
+    pub fn get_entity(&self, key: u32) -> Option<u32> {
+        self.board.get_entity(self.namespace.key(key)?)
+    }
+
+    /// Writes `value` under the namespaced `key`, returning `false` if `key` is out of range.
+    pub fn set(&mut self, key: u32, value: BlackboardValue) -> bool {
+        match self.namespace.key(key) {
+            Some(key) => {
+                self.board.set(key, value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn set_int(&mut self, key: u32, value: i32) -> bool {
+        self.set(key, BlackboardValue::Int(value))
+    }
+
+    pub fn set_float(&mut self, key: u32, value: f32) -> bool {
+        self.set(key, BlackboardValue::from_f32(value))
+    }
+
+    pub fn set_bool(&mut self, key: u32, value: bool) -> bool {
+        self.set(key, BlackboardValue::Bool(value))
+    }
+
+    pub fn set_entity(&mut self, key: u32, value: u32) -> bool {
+        self.set(key, BlackboardValue::Entity(value))
+    }
+
+    pub fn has(&self, key: u32) -> bool {
+        self.namespace
+            .key(key)
+            .is_some_and(|key| self.board.has(key))
+    }
+
+    pub fn remove(&mut self, key: u32) -> Option<BlackboardValue> {
+        self.board.remove(self.namespace.key(key)?)
+    }
+}
+
+/// Key/value storage shared by the map-backed and fixed-capacity blackboards.
+pub trait BlackboardStore {
+    fn get(&self, key: u32) -> Option<BlackboardValue>;
+    /// Stores `value` under `key`, returning `false` if the store had no room for it.
+    fn set(&mut self, key: u32, value: BlackboardValue) -> bool;
+    fn remove(&mut self, key: u32) -> Option<BlackboardValue>;
+    fn clear(&mut self);
+    fn len(&self) -> usize;
+
+    fn has(&self, key: u32) -> bool {
+        self.get(key).is_some()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl BlackboardStore for Blackboard {
+    fn get(&self, key: u32) -> Option<BlackboardValue> {
+        Blackboard::get(self, key)
+    }
+
+    fn set(&mut self, key: u32, value: BlackboardValue) -> bool {
+        Blackboard::set(self, key, value);
+        true
+    }
+
+    fn remove(&mut self, key: u32) -> Option<BlackboardValue> {
+        Blackboard::remove(self, key)
+    }
+
+    fn clear(&mut self) {
+        Blackboard::clear(self);
+    }
+
+    fn len(&self) -> usize {
+        Blackboard::len(self)
+    }
+}
+
+/// Allocation-free blackboard holding at most `N` entries, searched linearly.
+#[derive(Clone, Debug)]
+pub struct ArrayBlackboard<const N: usize> {
+    entries: [(u32, BlackboardValue); N],
+    len: usize,
+}
+
+impl<const N: usize> ArrayBlackboard<N> {
+    pub fn new() -> Self {
+        Self {
+            entries: [(0, BlackboardValue::Int(0)); N],
+            len: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    fn position(&self, key: u32) -> Option<usize> {
+        self.entries[..self.len].iter().position(|(k, _)| *k == key)
+    }
+}
+
+impl<const N: usize> Default for ArrayBlackboard<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> BlackboardStore for ArrayBlackboard<N> {
+    fn get(&self, key: u32) -> Option<BlackboardValue> {
+        self.position(key).map(|i| self.entries[i].1)
+    }
+
+    fn set(&mut self, key: u32, value: BlackboardValue) -> bool {
+        if let Some(i) = self.position(key) {
+            self.entries[i].1 = value;
+            true
+        } else if self.is_full() {
+            false
+        } else {
+            self.entries[self.len] = (key, value);
+            self.len += 1;
+            true
+        }
+    }
+
+    fn remove(&mut self, key: u32) -> Option<BlackboardValue> {
+        let i = self.position(key)?;
+        let value = self.entries[i].1;
+        self.len -= 1;
+        self.entries[i] = self.entries[self.len];
+        Some(value)
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Blackboard, BlackboardValue};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::{ArrayBlackboard, Blackboard, BlackboardStore, BlackboardValue, KeyNamespace};
 
     fn approx_eq(left: f32, right: f32) {
         assert!((left - right).abs() < 1.0e-6, "{left} != {right}");
@@ -280,4 +752,315 @@ mod tests {
     fn blackboard_from_f32() {
         assert_eq!(BlackboardValue::from_f32(1.5), BlackboardValue::Fixed(1500));
     }
+
+    #[test]
+    fn array_blackboard_fills_to_capacity() {
+        let mut bb = ArrayBlackboard::<3>::new();
+        assert!(bb.set(1, BlackboardValue::Int(1)));
+        assert!(bb.set(2, BlackboardValue::Int(2)));
+        assert!(bb.set(3, BlackboardValue::Int(3)));
+        assert!(bb.is_full());
+        assert_eq!(bb.len(), 3);
+
+        assert!(!bb.set(4, BlackboardValue::Int(4)));
+        assert!(!bb.has(4));
+        assert!(bb.set(2, BlackboardValue::Bool(true)));
+        assert_eq!(bb.get(2), Some(BlackboardValue::Bool(true)));
+    }
+
+    #[test]
+    fn array_blackboard_remove_frees_slot() {
+        let mut bb = ArrayBlackboard::<2>::new();
+        bb.set(1, BlackboardValue::Int(1));
+        bb.set(2, BlackboardValue::Int(2));
+        assert_eq!(bb.remove(1), Some(BlackboardValue::Int(1)));
+        assert_eq!(bb.get(2), Some(BlackboardValue::Int(2)));
+        assert!(bb.set(3, BlackboardValue::Int(3)));
+        assert!(bb.is_full());
+        bb.clear();
+        assert!(bb.is_empty());
+    }
+
+    #[test]
+    fn blackboard_store_map_backend() {
+        let mut bb = Blackboard::new();
+        assert!(BlackboardStore::set(&mut bb, 1, BlackboardValue::Int(5)));
+        assert_eq!(BlackboardStore::get(&bb, 1), Some(BlackboardValue::Int(5)));
+        assert_eq!(BlackboardStore::len(&bb), 1);
+    }
+
+    #[test]
+    fn blackboard_iter_sorted_keys() {
+        let mut bb = Blackboard::new();
+        bb.set_int(3, 30);
+        bb.set_bool(1, true);
+        bb.set_entity(2, 7);
+        let entries: Vec<_> = bb.iter().collect();
+        assert_eq!(
+            entries,
+            vec![
+                (1, BlackboardValue::Bool(true)),
+                (2, BlackboardValue::Entity(7)),
+                (3, BlackboardValue::Int(30)),
+            ]
+        );
+        assert_eq!(bb.keys().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn blackboard_retain_even_keys() {
+        let mut bb = Blackboard::new();
+        for key in 1..=6 {
+            bb.set_int(key, key as i32);
+        }
+        bb.retain(|key, _| key % 2 == 0);
+        assert_eq!(bb.keys().collect::<Vec<_>>(), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn blackboard_add_int_missing_key() {
+        let mut bb = Blackboard::new();
+        assert_eq!(bb.add_int(1, 5), Some(5));
+        assert_eq!(bb.add_int(1, -2), Some(3));
+        assert_eq!(bb.get_int(1), Some(3));
+    }
+
+    #[test]
+    fn blackboard_add_float_missing_key() {
+        let mut bb = Blackboard::new();
+        approx_eq(bb.add_float(1, 0.25).unwrap_or_default(), 0.25);
+        approx_eq(bb.add_float(1, 1.5).unwrap_or_default(), 1.75);
+        approx_eq(bb.get_float(1).unwrap_or_default(), 1.75);
+    }
+
+    #[test]
+    fn blackboard_toggle_bool_twice() {
+        let mut bb = Blackboard::new();
+        assert_eq!(bb.toggle_bool(4), Some(true));
+        assert_eq!(bb.toggle_bool(4), Some(false));
+        assert_eq!(bb.get_bool(4), Some(false));
+    }
+
+    #[test]
+    fn blackboard_arithmetic_leaves_mismatched_types() {
+        let mut bb = Blackboard::new();
+        bb.set_bool(1, true);
+        bb.set_int(2, 7);
+        bb.set_real_float(3, 0.5);
+
+        assert_eq!(bb.add_int(1, 5), None);
+        assert_eq!(bb.get_bool(1), Some(true));
+        assert_eq!(bb.add_float(2, 1.0), None);
+        assert_eq!(bb.get_int(2), Some(7));
+        assert_eq!(bb.add_float(3, 1.0), None);
+        assert_eq!(bb.get_real_float(3), Some(0.5));
+        assert_eq!(bb.toggle_bool(2), None);
+        assert_eq!(bb.get_int(2), Some(7));
+    }
+
+    #[test]
+    fn blackboard_set_int_clamped() {
+        let mut bb = Blackboard::new();
+        bb.set_int_clamped(1, 150, 0, 100);
+        assert_eq!(bb.get_int(1), Some(100));
+        bb.set_int_clamped(1, -5, 0, 100);
+        assert_eq!(bb.get_int(1), Some(0));
+        bb.set_int_clamped(1, 42, 0, 100);
+        assert_eq!(bb.get_int(1), Some(42));
+        bb.set_int_clamped(1, 150, 100, 0);
+        assert_eq!(bb.get_int(1), Some(100));
+    }
+
+    #[test]
+    fn blackboard_set_float_clamped() {
+        let mut bb = Blackboard::new();
+        bb.set_float_clamped(1, 1.5, 0.0, 1.0);
+        approx_eq(bb.get_float(1).unwrap_or_default(), 1.0);
+        bb.set_float_clamped(1, -0.5, 0.0, 1.0);
+        approx_eq(bb.get_float(1).unwrap_or_default(), 0.0);
+        bb.set_float_clamped(1, 0.25, 0.0, 1.0);
+        approx_eq(bb.get_float(1).unwrap_or_default(), 0.25);
+        bb.set_float_clamped(1, 1.5, 1.0, 0.0);
+        approx_eq(bb.get_float(1).unwrap_or_default(), 1.0);
+        bb.set_float_clamped(1, 1.5, f32::NAN, 1.0);
+        approx_eq(bb.get_float(1).unwrap_or_default(), 1.0);
+        bb.set_float_clamped(1, -3.0, f32::NAN, f32::NAN);
+        approx_eq(bb.get_float(1).unwrap_or_default(), -3.0);
+    }
+
+    #[test]
+    fn blackboard_coerce_int() {
+        assert_eq!(BlackboardValue::Int(-4).coerce_int(), Some(-4));
+        assert_eq!(BlackboardValue::Fixed(2750).coerce_int(), Some(2));
+        assert_eq!(BlackboardValue::Fixed(-2750).coerce_int(), Some(-2));
+        assert_eq!(BlackboardValue::Bool(true).coerce_int(), Some(1));
+        assert_eq!(BlackboardValue::Bool(false).coerce_int(), Some(0));
+        assert_eq!(BlackboardValue::Entity(12).coerce_int(), Some(12));
+        assert_eq!(
+            BlackboardValue::Entity(u32::MAX).coerce_int(),
+            Some(i32::MAX)
+        );
+        assert_eq!(BlackboardValue::Vec2(1, 2).coerce_int(), None);
+    }
+
+    #[test]
+    fn blackboard_coerce_float() {
+        approx_eq(
+            BlackboardValue::Int(3).coerce_float().unwrap_or_default(),
+            3.0,
+        );
+        approx_eq(
+            BlackboardValue::Fixed(1250)
+                .coerce_float()
+                .unwrap_or_default(),
+            1.25,
+        );
+        approx_eq(
+            BlackboardValue::Bool(true)
+                .coerce_float()
+                .unwrap_or_default(),
+            1.0,
+        );
+        approx_eq(
+            BlackboardValue::Bool(false).coerce_float().unwrap_or(-1.0),
+            0.0,
+        );
+        approx_eq(
+            BlackboardValue::Entity(7)
+                .coerce_float()
+                .unwrap_or_default(),
+            7.0,
+        );
+        assert_eq!(BlackboardValue::Vec2(1, 2).coerce_float(), None);
+    }
+
+    #[test]
+    fn blackboard_vec2_helpers() {
+        let v = BlackboardValue::Vec2(3, -4);
+        approx_eq(v.magnitude().unwrap_or_default(), 5.0);
+        assert_eq!(v.manhattan(), Some(7));
+        approx_eq(v.distance_to((0, 0)).unwrap_or_default(), 5.0);
+        assert_eq!(BlackboardValue::Int(3).magnitude(), None);
+        assert_eq!(BlackboardValue::Bool(true).manhattan(), None);
+    }
+
+    #[test]
+    fn blackboard_vec2_distance_between_keys() {
+        let mut bb = Blackboard::new();
+        bb.set_vec2(1, 1, 1);
+        bb.set_vec2(2, 7, 9);
+        bb.set_int(3, 5);
+        approx_eq(bb.vec2_distance(1, 2).unwrap_or_default(), 10.0);
+        assert_eq!(bb.vec2_distance(1, 3), None);
+        assert_eq!(bb.vec2_distance(1, 4), None);
+    }
+
+    #[test]
+    fn blackboard_real_float_keeps_precision() {
+        let mut bb = Blackboard::new();
+        bb.set_float(1, 0.123456);
+        bb.set_real_float(2, 0.123456);
+
+        let fixed = bb.get_float(1).unwrap_or_default();
+        let real = bb.get_real_float(2).unwrap_or_default();
+        assert!((fixed - 0.123456).abs() > 1.0e-4);
+        assert_eq!(real, 0.123456);
+        assert_eq!(bb.get_float(2), None);
+        assert_eq!(bb.get_real_float(1), None);
+
+        let value = BlackboardValue::real_float(0.123456);
+        assert!(value.is_truthy());
+        assert!(!BlackboardValue::real_float(0.0).is_truthy());
+        assert_eq!(value.to_score_f32(), 0.123456);
+        assert_eq!(value.coerce_float(), Some(0.123456));
+    }
+
+    #[test]
+    fn blackboard_entity_lists() {
+        let mut bb = Blackboard::new();
+        assert_eq!(bb.get_entities(1), None);
+
+        bb.set_entities(1, &[10, 20]);
+        bb.push_entity(1, 30);
+        bb.push_entity(1, 20);
+        bb.push_entity(2, 5);
+        assert_eq!(bb.get_entities(1), Some(&[10, 20, 30][..]));
+        assert_eq!(bb.get_entities(2), Some(&[5][..]));
+
+        assert!(bb.remove_entity(1, 20));
+        assert!(!bb.remove_entity(1, 99));
+        assert!(!bb.remove_entity(7, 1));
+        assert_eq!(bb.get_entities(1), Some(&[10, 30][..]));
+        assert!(!bb.has(1));
+
+        assert_eq!(bb.clear_entities(2), Some(vec![5]));
+        bb.clear();
+        assert_eq!(bb.get_entities(1), None);
+    }
+
+    #[test]
+    fn blackboard_namespaces_do_not_collide() {
+        const COMBAT: KeyNamespace = KeyNamespace::new(100, 100);
+        const NAV: KeyNamespace = KeyNamespace::new(200, 50);
+        let mut bb = Blackboard::new();
+        assert!(bb.namespace(COMBAT).set_int(5, 1));
+        assert!(bb.namespace(NAV).set_int(5, 2));
+
+        assert_eq!(COMBAT.key(5), Some(105));
+        assert_eq!(bb.get_int(105), Some(1));
+        assert_eq!(bb.get_int(205), Some(2));
+        assert_eq!(bb.get(5), None);
+
+        let mut combat = bb.namespace(COMBAT);
+        assert_eq!(combat.namespace().base(), 100);
+        assert_eq!(combat.get_int(5), Some(1));
+        assert_eq!(combat.remove(5), Some(BlackboardValue::Int(1)));
+        assert!(!combat.has(5));
+        assert_eq!(bb.get_int(205), Some(2));
+    }
+
+    #[test]
+    fn blackboard_namespace_rejects_keys_outside_its_range() {
+        const COMBAT: KeyNamespace = KeyNamespace::new(100, 100);
+        let mut bb = Blackboard::new();
+        bb.namespace(KeyNamespace::new(200, 50)).set_int(5, 2);
+
+        // Local key 105 would land on the neighbouring namespace's key 205.
+        assert_eq!(COMBAT.key(105), None);
+        let mut combat = bb.namespace(COMBAT);
+        assert!(!combat.set_int(105, 1));
+        assert_eq!(combat.get_int(105), None);
+        assert!(!combat.has(105));
+        assert_eq!(combat.remove(105), None);
+        assert_eq!(bb.get_int(205), Some(2));
+
+        assert_eq!(KeyNamespace::new(u32::MAX, 10).key(1), None);
+        assert_eq!(KeyNamespace::new(u32::MAX, 10).key(0), Some(u32::MAX));
+    }
+
+    #[test]
+    fn blackboard_dirty_tracks_writes() {
+        let mut bb = Blackboard::new();
+        bb.set_int(9, 1);
+        bb.track_dirty();
+        bb.set_int(1, 10);
+        bb.set_float(2, 0.5);
+        bb.set_bool(3, true);
+        bb.set_int(1, 11);
+        bb.remove(4);
+        assert_eq!(
+            bb.take_dirty().into_iter().collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(bb.take_dirty().is_empty());
+
+        bb.remove(9);
+        assert_eq!(bb.take_dirty().into_iter().collect::<Vec<_>>(), vec![9]);
+
+        bb.retain(|key, _| key != 2);
+        assert_eq!(bb.take_dirty().into_iter().collect::<Vec<_>>(), vec![2]);
+
+        bb.clear();
+        assert_eq!(bb.take_dirty().into_iter().collect::<Vec<_>>(), vec![1, 3]);
+    }
 }
@@ -1,12 +1,17 @@
 use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 
-use crate::{BehaviorNode, Decorator, ParallelPolicy};
+use crate::tick::{node_children, subtree_size};
+use crate::{validate_tree, BehaviorNode, BlackboardValue, Decorator, ParallelPolicy, TreeError};
 
 pub struct TreeBuilder<A, C> {
     stack: Vec<BuilderFrame<A, C>>,
     root: Option<BehaviorNode<A, C>>,
     pending_decorators: Vec<Decorator>,
+    pending_label: Option<&'static str>,
+    labels: BTreeMap<usize, &'static str>,
+    allow_empty_composites: bool,
 }
 
 struct BuilderFrame<A, C> {
@@ -18,14 +23,20 @@ struct BuilderFrame<A, C> {
 enum CompositeType {
     Sequence,
     Selector,
-    Parallel(ParallelPolicy),
+    ReactiveSelector,
+    Parallel(ParallelPolicy, bool),
     RandomSelector,
     WeightedSelector,
+    UtilitySelector,
 }
 
 #[derive(Default)]
 struct FrameMetadata {
     weights: Vec<u32>,
+    utility_ids: Vec<u32>,
+    label: Option<&'static str>,
+    require_percent: Option<u8>,
+    seed: Option<u32>,
 }
 
 impl<A, C> TreeBuilder<A, C> {
@@ -34,14 +45,26 @@ impl<A, C> TreeBuilder<A, C> {
             stack: Vec::new(),
             root: None,
             pending_decorators: Vec::new(),
+            pending_label: None,
+            labels: BTreeMap::new(),
+            allow_empty_composites: false,
         }
     }
 
+    /// Lets [`try_build`](Self::try_build) accept composites with no children.
+    pub fn allow_empty_composites(mut self) -> Self {
+        self.allow_empty_composites = true;
+        self
+    }
+
     pub fn sequence(mut self) -> Self {
         self.stack.push(BuilderFrame {
             node_type: CompositeType::Sequence,
             children: Vec::new(),
-            metadata: FrameMetadata::default(),
+            metadata: FrameMetadata {
+                label: self.pending_label.take(),
+                ..FrameMetadata::default()
+            },
         });
         self
     }
@@ -50,25 +73,69 @@ impl<A, C> TreeBuilder<A, C> {
         self.stack.push(BuilderFrame {
             node_type: CompositeType::Selector,
             children: Vec::new(),
-            metadata: FrameMetadata::default(),
+            metadata: FrameMetadata {
+                label: self.pending_label.take(),
+                ..FrameMetadata::default()
+            },
         });
         self
     }
 
-    pub fn parallel(mut self, policy: ParallelPolicy) -> Self {
+    pub fn reactive_selector(mut self) -> Self {
         self.stack.push(BuilderFrame {
-            node_type: CompositeType::Parallel(policy),
+            node_type: CompositeType::ReactiveSelector,
             children: Vec::new(),
-            metadata: FrameMetadata::default(),
+            metadata: FrameMetadata {
+                label: self.pending_label.take(),
+                ..FrameMetadata::default()
+            },
+        });
+        self
+    }
+
+    pub fn parallel(self, policy: ParallelPolicy) -> Self {
+        self.open_parallel(policy, false)
+    }
+
+    /// Opens a parallel that stops ticking children once `policy` has decided the outcome.
+    pub fn parallel_short_circuit(self, policy: ParallelPolicy) -> Self {
+        self.open_parallel(policy, true)
+    }
+
+    fn open_parallel(mut self, policy: ParallelPolicy, short_circuit: bool) -> Self {
+        self.stack.push(BuilderFrame {
+            node_type: CompositeType::Parallel(policy, short_circuit),
+            children: Vec::new(),
+            metadata: FrameMetadata {
+                label: self.pending_label.take(),
+                ..FrameMetadata::default()
+            },
         });
         self
     }
 
+    pub fn parallel_require_n(self, n: usize) -> Self {
+        self.parallel(ParallelPolicy::RequireN(n))
+    }
+
+    /// Opens a parallel requiring `pct` percent of its children, resolved at `end()` to
+    /// `RequireN((children * pct / 100).max(1))`.
+    pub fn parallel_percent(self, pct: u8) -> Self {
+        let mut builder = self.open_parallel(ParallelPolicy::RequireAll, false);
+        if let Some(frame) = builder.stack.last_mut() {
+            frame.metadata.require_percent = Some(pct.min(100));
+        }
+        builder
+    }
+
     pub fn random_selector(mut self) -> Self {
         self.stack.push(BuilderFrame {
             node_type: CompositeType::RandomSelector,
             children: Vec::new(),
-            metadata: FrameMetadata::default(),
+            metadata: FrameMetadata {
+                label: self.pending_label.take(),
+                ..FrameMetadata::default()
+            },
         });
         self
     }
@@ -77,7 +144,22 @@ impl<A, C> TreeBuilder<A, C> {
         self.stack.push(BuilderFrame {
             node_type: CompositeType::WeightedSelector,
             children: Vec::new(),
-            metadata: FrameMetadata::default(),
+            metadata: FrameMetadata {
+                label: self.pending_label.take(),
+                ..FrameMetadata::default()
+            },
+        });
+        self
+    }
+
+    pub fn utility_selector(mut self) -> Self {
+        self.stack.push(BuilderFrame {
+            node_type: CompositeType::UtilitySelector,
+            children: Vec::new(),
+            metadata: FrameMetadata {
+                label: self.pending_label.take(),
+                ..FrameMetadata::default()
+            },
         });
         self
     }
@@ -92,16 +174,86 @@ impl<A, C> TreeBuilder<A, C> {
         self
     }
 
+    pub fn blackboard_condition(mut self, key: u32) -> Self {
+        self.push_node(BehaviorNode::BlackboardCondition(key));
+        self
+    }
+
+    pub fn set_blackboard(mut self, key: u32, value: BlackboardValue) -> Self {
+        self.push_node(BehaviorNode::SetBlackboard { key, value });
+        self
+    }
+
+    pub fn clear_blackboard(mut self, key: u32) -> Self {
+        self.push_node(BehaviorNode::ClearBlackboard(key));
+        self
+    }
+
     pub fn wait(mut self, ticks: u32) -> Self {
         self.push_node(BehaviorNode::Wait(ticks));
         self
     }
 
+    pub fn wait_millis(mut self, millis: u32) -> Self {
+        self.push_node(BehaviorNode::WaitMillis(millis));
+        self
+    }
+
+    pub fn throttle(mut self, interval: u32) -> Self {
+        self.push_node(BehaviorNode::Throttle(interval));
+        self
+    }
+
+    pub fn wait_random(mut self, min: u32, max: u32) -> Self {
+        self.push_node(BehaviorNode::WaitRandom { min, max });
+        self
+    }
+
+    /// Splices an already-built node (e.g. a preset) into the current composite.
+    pub fn subtree(mut self, node: BehaviorNode<A, C>) -> Self {
+        self.push_node(node);
+        self
+    }
+
     pub fn decorator(mut self, decorator: Decorator) -> Self {
         self.pending_decorators.push(decorator);
         self
     }
 
+    pub fn repeat(self, times: u32) -> Self {
+        self.decorator(Decorator::Repeat(times))
+    }
+
+    pub fn retry(self, attempts: u32) -> Self {
+        self.decorator(Decorator::Retry(attempts))
+    }
+
+    pub fn invert(self) -> Self {
+        self.decorator(Decorator::Inverter)
+    }
+
+    pub fn timeout(self, ticks: u32) -> Self {
+        self.decorator(Decorator::Timeout(ticks))
+    }
+
+    pub fn cooldown(self, ticks: u32) -> Self {
+        self.decorator(Decorator::Cooldown(ticks))
+    }
+
+    pub fn delay(self, ticks: u32) -> Self {
+        self.decorator(Decorator::Delay(ticks))
+    }
+
+    pub fn running_timeout(self, ticks: u32) -> Self {
+        self.decorator(Decorator::RunningTimeout(ticks))
+    }
+
+    /// Labels the next node pushed (including any pending decorators wrapping it).
+    pub fn label(mut self, label: &'static str) -> Self {
+        self.pending_label = Some(label);
+        self
+    }
+
     pub fn weight(mut self, weight: u32) -> Self {
         let frame = self
             .stack
@@ -114,6 +266,34 @@ impl<A, C> TreeBuilder<A, C> {
         self
     }
 
+    /// Gives the open random or weighted selector its own RNG stream; see
+    /// `BehaviorNode::RandomSelector`.
+    pub fn seed(mut self, seed: u32) -> Self {
+        let frame = self
+            .stack
+            .last_mut()
+            .expect("seed() requires an open composite");
+        match frame.node_type {
+            CompositeType::RandomSelector | CompositeType::WeightedSelector => {
+                frame.metadata.seed = Some(seed)
+            }
+            _ => panic!("seed() is only valid inside random_selector() or weighted_selector()"),
+        }
+        self
+    }
+
+    pub fn utility(mut self, key: u32) -> Self {
+        let frame = self
+            .stack
+            .last_mut()
+            .expect("utility() requires an open composite");
+        match frame.node_type {
+            CompositeType::UtilitySelector => frame.metadata.utility_ids.push(key),
+            _ => panic!("utility() is only valid inside utility_selector()"),
+        }
+        self
+    }
+
     pub fn end(mut self) -> Self {
         let frame = self
             .stack
@@ -122,11 +302,25 @@ impl<A, C> TreeBuilder<A, C> {
         let mut node = match frame.node_type {
             CompositeType::Sequence => BehaviorNode::Sequence(frame.children),
             CompositeType::Selector => BehaviorNode::Selector(frame.children),
-            CompositeType::Parallel(policy) => BehaviorNode::Parallel {
-                policy,
+            CompositeType::ReactiveSelector => BehaviorNode::ReactiveSelector(frame.children),
+            CompositeType::Parallel(policy, short_circuit) => {
+                let policy = match frame.metadata.require_percent {
+                    Some(pct) => {
+                        let n = frame.children.len() * usize::from(pct) / 100;
+                        ParallelPolicy::RequireN(n.max(1))
+                    }
+                    None => policy,
+                };
+                BehaviorNode::Parallel {
+                    policy,
+                    children: frame.children,
+                    short_circuit,
+                }
+            }
+            CompositeType::RandomSelector => BehaviorNode::RandomSelector {
                 children: frame.children,
+                seed: frame.metadata.seed,
             },
-            CompositeType::RandomSelector => BehaviorNode::RandomSelector(frame.children),
             CompositeType::WeightedSelector => {
                 if frame.children.len() != frame.metadata.weights.len() {
                     panic!(
@@ -138,11 +332,28 @@ impl<A, C> TreeBuilder<A, C> {
                 BehaviorNode::WeightedSelector {
                     children: frame.children,
                     weights: frame.metadata.weights,
+                    seed: frame.metadata.seed,
+                }
+            }
+            CompositeType::UtilitySelector => {
+                if frame.children.len() != frame.metadata.utility_ids.len() {
+                    panic!(
+                        "utility_selector children/utility ids mismatch: {} children, {} ids",
+                        frame.children.len(),
+                        frame.metadata.utility_ids.len()
+                    );
+                }
+                BehaviorNode::UtilitySelector {
+                    children: frame.children,
+                    utility_ids: frame.metadata.utility_ids,
                 }
             }
         };
 
         node = self.wrap_with_pending_decorators(node);
+        if let Some(label) = frame.metadata.label {
+            self.labels.insert(self.next_node_id(), label);
+        }
         if let Some(parent) = self.stack.last_mut() {
             parent.children.push(node);
         } else {
@@ -151,7 +362,12 @@ impl<A, C> TreeBuilder<A, C> {
         self
     }
 
-    pub fn build(mut self) -> BehaviorNode<A, C> {
+    pub fn build(self) -> BehaviorNode<A, C> {
+        self.build_with_labels().0
+    }
+
+    /// Builds the tree along with the labels attached via [`label`](Self::label), keyed by node id.
+    pub fn build_with_labels(mut self) -> (BehaviorNode<A, C>, BTreeMap<usize, &'static str>) {
         if !self.stack.is_empty() {
             panic!("build() with unclosed composites: {}", self.stack.len());
         }
@@ -161,11 +377,51 @@ impl<A, C> TreeBuilder<A, C> {
                 self.pending_decorators.len()
             );
         }
-        self.root.take().expect("build() requires at least one node")
+        let root = self
+            .root
+            .take()
+            .expect("build() requires at least one node");
+        match validate_tree(&root) {
+            Ok(()) => {}
+            Err(TreeError::ParallelThresholdTooHigh { required, children }) => {
+                panic!("parallel RequireN({required}) exceeds child count {children}")
+            }
+            Err(err) => panic!("build() produced an invalid tree: {err:?}"),
+        }
+        (root, self.labels)
+    }
+
+    /// Like [`build`](Self::build), but reports problems as a [`TreeError`] instead of
+    /// panicking, including composites left without children and failed
+    /// [`validate_tree`] checks.
+    pub fn try_build(self) -> Result<BehaviorNode<A, C>, TreeError> {
+        if !self.stack.is_empty() {
+            return Err(TreeError::UnbalancedBuilder(self.stack.len()));
+        }
+        if !self.pending_decorators.is_empty() {
+            return Err(TreeError::UnbalancedBuilder(self.pending_decorators.len()));
+        }
+        let root = self.root.ok_or(TreeError::EmptyComposite)?;
+        validate_tree(&root)?;
+        if !self.allow_empty_composites && has_empty_composite(&root) {
+            return Err(TreeError::EmptyComposite);
+        }
+        Ok(root)
+    }
+
+    /// Pre-order id the next completed node will receive in the finished tree.
+    fn next_node_id(&self) -> usize {
+        self.stack
+            .iter()
+            .map(|frame| 1 + frame.children.iter().map(subtree_size).sum::<usize>())
+            .sum()
     }
 
     fn push_node(&mut self, node: BehaviorNode<A, C>) {
         let node = self.wrap_with_pending_decorators(node);
+        if let Some(label) = self.pending_label.take() {
+            self.labels.insert(self.next_node_id(), label);
+        }
         if let Some(frame) = self.stack.last_mut() {
             frame.children.push(node);
         } else {
@@ -191,6 +447,38 @@ impl<A, C> TreeBuilder<A, C> {
     }
 }
 
+pub(crate) fn has_empty_composite<A, C>(node: &BehaviorNode<A, C>) -> bool {
+    let mut children = node_children(node);
+    let is_composite = match node {
+        BehaviorNode::Decorator { .. }
+        | BehaviorNode::TimedSelector { .. }
+        | BehaviorNode::Action(_)
+        | BehaviorNode::Condition(_)
+        | BehaviorNode::BlackboardCondition(_)
+        | BehaviorNode::Compare { .. }
+        | BehaviorNode::SetBlackboard { .. }
+        | BehaviorNode::ClearBlackboard(_)
+        | BehaviorNode::Wait(_)
+        | BehaviorNode::WaitMillis(_)
+        | BehaviorNode::WaitRandom { .. }
+        | BehaviorNode::Throttle(_)
+        | BehaviorNode::SubtreeRef(_) => false,
+        BehaviorNode::Sequence(_)
+        | BehaviorNode::Selector(_)
+        | BehaviorNode::ReactiveSelector(_)
+        | BehaviorNode::Parallel { .. }
+        | BehaviorNode::InterruptParallel { .. }
+        | BehaviorNode::UtilitySelector { .. }
+        | BehaviorNode::RandomSelector { .. }
+        | BehaviorNode::ShuffledSelector { .. }
+        | BehaviorNode::ShuffledSequence { .. }
+        | BehaviorNode::WeightedSelector { .. }
+        | BehaviorNode::BlackboardWeightedSelector { .. }
+        | BehaviorNode::Reasoner { .. } => true,
+    };
+    (is_composite && children.len() == 0) || children.any(has_empty_composite)
+}
+
 impl<A, C> Default for TreeBuilder<A, C> {
     fn default() -> Self {
         Self::new()
@@ -199,9 +487,10 @@ impl<A, C> Default for TreeBuilder<A, C> {
 
 #[cfg(test)]
 mod tests {
+    use alloc::boxed::Box;
     use alloc::vec;
 
-    use crate::{BehaviorNode, Decorator, TreeBuilder};
+    use crate::{preset, BehaviorNode, Decorator, ParallelPolicy, TreeBuilder, TreeError};
 
     #[test]
     fn builder_simple_sequence() {
@@ -297,11 +586,303 @@ mod tests {
             .end()
             .build();
         match tree {
-            BehaviorNode::WeightedSelector { children, weights } => {
+            BehaviorNode::WeightedSelector {
+                children, weights, ..
+            } => {
                 assert_eq!(children.len(), 2);
                 assert_eq!(weights, vec![10, 1]);
             }
             _ => panic!("expected weighted selector"),
         }
     }
+
+    #[test]
+    fn builder_reactive_selector() {
+        let tree: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .reactive_selector()
+            .condition(1u32)
+            .action(2u32)
+            .end()
+            .build();
+        match tree {
+            BehaviorNode::ReactiveSelector(children) => assert_eq!(children.len(), 2),
+            _ => panic!("expected reactive selector"),
+        }
+    }
+
+    #[test]
+    fn builder_embeds_subtree() {
+        let tree: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .selector()
+            .decorator(Decorator::Guard(5))
+            .subtree(preset::combat_melee())
+            .action(9u32)
+            .end()
+            .build();
+        match tree {
+            BehaviorNode::Selector(children) => {
+                assert_eq!(children.len(), 2);
+                match &children[0] {
+                    BehaviorNode::Decorator { decorator, child } => {
+                        assert_eq!(*decorator, Decorator::Guard(5));
+                        assert_eq!(**child, preset::combat_melee());
+                    }
+                    _ => panic!("expected decorated subtree"),
+                }
+                assert!(matches!(children[1], BehaviorNode::Action(9)));
+            }
+            _ => panic!("expected selector"),
+        }
+    }
+
+    #[test]
+    fn builder_utility_selector() {
+        let tree: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .utility_selector()
+            .action(1u32)
+            .utility(100)
+            .action(2u32)
+            .utility(101)
+            .end()
+            .build();
+        match tree {
+            BehaviorNode::UtilitySelector {
+                children,
+                utility_ids,
+            } => {
+                assert_eq!(children.len(), 2);
+                assert_eq!(utility_ids, vec![100, 101]);
+            }
+            _ => panic!("expected utility selector"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "utility ids mismatch")]
+    fn builder_utility_selector_mismatch_panics() {
+        let _: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .utility_selector()
+            .action(1u32)
+            .utility(100)
+            .action(2u32)
+            .end()
+            .build();
+    }
+
+    #[test]
+    fn builder_parallel_require_n() {
+        let tree: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .parallel_require_n(2)
+            .action(1u32)
+            .action(2u32)
+            .action(3u32)
+            .end()
+            .build();
+        match tree {
+            BehaviorNode::Parallel {
+                policy, children, ..
+            } => {
+                assert_eq!(policy, ParallelPolicy::RequireN(2));
+                assert_eq!(children.len(), 3);
+            }
+            _ => panic!("expected parallel"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "RequireN(5) exceeds child count 2")]
+    fn builder_parallel_require_n_too_high_panics() {
+        let _: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .parallel_require_n(5)
+            .action(1u32)
+            .action(2u32)
+            .end()
+            .build();
+    }
+
+    #[test]
+    fn builder_labels_use_preorder_ids() {
+        let (_, labels) = TreeBuilder::<u32, u32>::new()
+            .sequence()
+            .label("guarded")
+            .decorator(Decorator::Inverter)
+            .condition(1u32)
+            .label("inner")
+            .selector()
+            .action(2u32)
+            .label("last")
+            .action(3u32)
+            .end()
+            .end()
+            .build_with_labels();
+        assert_eq!(labels.get(&1), Some(&"guarded"));
+        assert_eq!(labels.get(&3), Some(&"inner"));
+        assert_eq!(labels.get(&5), Some(&"last"));
+        assert_eq!(labels.len(), 3);
+    }
+
+    #[test]
+    fn builder_try_build_rejects_empty_composite() {
+        let result = TreeBuilder::<u32, u32>::new()
+            .selector()
+            .action(1)
+            .sequence()
+            .end()
+            .end()
+            .try_build();
+        assert_eq!(result, Err(TreeError::EmptyComposite));
+    }
+
+    #[test]
+    fn builder_try_build_allows_empty_composite_when_opted_in() {
+        let tree = TreeBuilder::<u32, u32>::new()
+            .allow_empty_composites()
+            .sequence()
+            .end()
+            .try_build();
+        assert_eq!(tree, Ok(BehaviorNode::Sequence(vec![])));
+    }
+
+    #[test]
+    fn builder_try_build_reports_unclosed_composite() {
+        let result = TreeBuilder::<u32, u32>::new()
+            .sequence()
+            .action(1)
+            .try_build();
+        assert_eq!(result, Err(TreeError::UnbalancedBuilder(1)));
+    }
+
+    #[test]
+    fn builder_decorator_shorthands() {
+        let tree: BehaviorNode<u32, u32> = TreeBuilder::new().invert().condition(1u32).build();
+        assert_eq!(
+            tree,
+            BehaviorNode::Decorator {
+                decorator: Decorator::Inverter,
+                child: Box::new(BehaviorNode::Condition(1)),
+            }
+        );
+
+        let tree: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .sequence()
+            .repeat(3)
+            .retry(2)
+            .action(1u32)
+            .timeout(4)
+            .cooldown(5)
+            .action(2u32)
+            .end()
+            .build();
+        let expected = TreeBuilder::new()
+            .sequence()
+            .decorator(Decorator::Repeat(3))
+            .decorator(Decorator::Retry(2))
+            .action(1u32)
+            .decorator(Decorator::Timeout(4))
+            .decorator(Decorator::Cooldown(5))
+            .action(2u32)
+            .end()
+            .build();
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn builder_parallel_short_circuit() {
+        let tree: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .parallel_short_circuit(ParallelPolicy::RequireAll)
+            .action(1)
+            .action(2)
+            .end()
+            .build();
+
+        assert!(matches!(
+            tree,
+            BehaviorNode::Parallel {
+                short_circuit: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn builder_parallel_percent_resolves_at_end() {
+        let tree: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .parallel_percent(60)
+            .action(1)
+            .action(2)
+            .action(3)
+            .action(4)
+            .action(5)
+            .end()
+            .build();
+        match tree {
+            BehaviorNode::Parallel {
+                policy, children, ..
+            } => {
+                assert_eq!(policy, ParallelPolicy::RequireN(3));
+                assert_eq!(children.len(), 5);
+            }
+            _ => panic!("expected parallel"),
+        }
+
+        let tree: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .parallel_percent(10)
+            .action(1)
+            .action(2)
+            .end()
+            .build();
+        assert!(matches!(
+            tree,
+            BehaviorNode::Parallel {
+                policy: ParallelPolicy::RequireN(1),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn builder_try_build_rejects_require_n_too_high() {
+        let result: Result<BehaviorNode<u32, u32>, TreeError> = TreeBuilder::new()
+            .parallel_require_n(5)
+            .action(1u32)
+            .action(2u32)
+            .end()
+            .try_build();
+        assert_eq!(
+            result,
+            Err(TreeError::ParallelThresholdTooHigh {
+                required: 5,
+                children: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn builder_random_selector_seed() {
+        let tree: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .random_selector()
+            .seed(9)
+            .action(1)
+            .end()
+            .build();
+        assert!(matches!(
+            tree,
+            BehaviorNode::RandomSelector { seed: Some(9), .. }
+        ));
+    }
+
+    #[test]
+    fn builder_weighted_selector_seed() {
+        let tree: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .weighted_selector()
+            .seed(4)
+            .weight(1)
+            .action(1)
+            .end()
+            .build();
+        assert!(matches!(
+            tree,
+            BehaviorNode::WeightedSelector { seed: Some(4), .. }
+        ));
+    }
 }
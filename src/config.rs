@@ -1,7 +1,17 @@
+use crate::status::Status;
+
 #[derive(Clone, Debug)]
 pub struct TreeConfig {
     pub max_depth: usize,
     pub max_ticks_per_frame: usize,
+    /// Lets [`BehaviorTree::try_with_config`](crate::BehaviorTree::try_with_config) accept
+    /// composites with no children.
+    pub allow_empty_composites: bool,
+    /// Status an empty `Sequence` or `ShuffledSequence` ticks to.
+    pub empty_sequence_status: Status,
+    /// Status an empty `Selector`, `ReactiveSelector`, `ShuffledSelector` or `RandomSelector`
+    /// ticks to.
+    pub empty_selector_status: Status,
 }
 
 impl Default for TreeConfig {
@@ -9,6 +19,9 @@ impl Default for TreeConfig {
         Self {
             max_depth: 64,
             max_ticks_per_frame: 10_000,
+            allow_empty_composites: false,
+            empty_sequence_status: Status::Success,
+            empty_selector_status: Status::Failure,
         }
     }
 }
@@ -16,11 +29,15 @@ impl Default for TreeConfig {
 #[cfg(test)]
 mod tests {
     use super::TreeConfig;
+    use crate::Status;
 
     #[test]
     fn tree_config_defaults() {
         let cfg = TreeConfig::default();
         assert_eq!(cfg.max_depth, 64);
         assert_eq!(cfg.max_ticks_per_frame, 10_000);
+        assert!(!cfg.allow_empty_composites);
+        assert_eq!(cfg.empty_sequence_status, Status::Success);
+        assert_eq!(cfg.empty_selector_status, Status::Failure);
     }
 }
@@ -1,29 +1,80 @@
+use core::any::Any;
+
 use rand_core::RngCore;
 
 use crate::blackboard::Blackboard;
+use crate::status::Status;
+use crate::tick::TickScratch;
 
-pub struct Context<'a> {
+/// Per-tick view handed to handlers; `B` is the blackboard store the tree ticks against.
+pub struct Context<'a, B = Blackboard> {
     tick: u64,
     delta_ticks: u32,
-    blackboard: &'a mut Blackboard,
+    delta_millis: u32,
+    blackboard: &'a mut B,
     rng: Option<&'a mut dyn RngCore>,
+    leaf_budget: Option<u32>,
+    abort_requested: bool,
+    user_data: Option<&'a mut dyn Any>,
+    scratch: Option<&'a mut TickScratch>,
+    empty_sequence_status: Status,
+    empty_selector_status: Status,
+    subtree_depth: u32,
 }
 
-impl<'a> Context<'a> {
+impl<'a, B> Context<'a, B> {
     pub fn new(
         tick: u64,
         delta_ticks: u32,
-        blackboard: &'a mut Blackboard,
+        blackboard: &'a mut B,
         rng: Option<&'a mut dyn RngCore>,
     ) -> Self {
         Self {
             tick,
             delta_ticks,
+            delta_millis: 0,
             blackboard,
             rng,
+            leaf_budget: None,
+            abort_requested: false,
+            user_data: None,
+            scratch: None,
+            empty_sequence_status: Status::Success,
+            empty_selector_status: Status::Failure,
+            subtree_depth: 0,
         }
     }
 
+    /// Wall-clock time since the previous tick, for millisecond-based nodes.
+    pub fn with_delta_millis(mut self, delta_millis: u32) -> Self {
+        self.delta_millis = delta_millis;
+        self
+    }
+
+    pub fn with_leaf_budget(mut self, budget: Option<u32>) -> Self {
+        self.leaf_budget = budget;
+        self
+    }
+
+    /// Attaches caller-owned data (e.g. the owning entity) for handlers to downcast.
+    pub fn with_user_data(mut self, user_data: Option<&'a mut dyn Any>) -> Self {
+        self.user_data = user_data;
+        self
+    }
+
+    /// Lends reusable buffers to nodes that would otherwise allocate while scoring.
+    pub fn with_scratch(mut self, scratch: Option<&'a mut TickScratch>) -> Self {
+        self.scratch = scratch;
+        self
+    }
+
+    /// Statuses returned by sequence-like and selector-like composites with no children.
+    pub fn with_empty_composite_statuses(mut self, sequence: Status, selector: Status) -> Self {
+        self.empty_sequence_status = sequence;
+        self.empty_selector_status = selector;
+        self
+    }
+
     pub fn tick(&self) -> u64 {
         self.tick
     }
@@ -32,11 +83,15 @@ impl<'a> Context<'a> {
         self.delta_ticks
     }
 
-    pub fn blackboard(&self) -> &Blackboard {
+    pub fn delta_millis(&self) -> u32 {
+        self.delta_millis
+    }
+
+    pub fn blackboard(&self) -> &B {
         self.blackboard
     }
 
-    pub fn blackboard_mut(&mut self) -> &mut Blackboard {
+    pub fn blackboard_mut(&mut self) -> &mut B {
         self.blackboard
     }
 
@@ -49,6 +104,67 @@ impl<'a> Context<'a> {
     pub fn has_rng(&self) -> bool {
         self.rng.is_some()
     }
+
+    /// Returns the attached user data if it is present and of type `T`.
+    pub fn user_data<T: Any>(&mut self) -> Option<&mut T> {
+        self.user_data.as_deref_mut()?.downcast_mut::<T>()
+    }
+
+    pub fn has_user_data(&self) -> bool {
+        self.user_data.is_some()
+    }
+
+    pub fn empty_sequence_status(&self) -> Status {
+        self.empty_sequence_status
+    }
+
+    pub fn empty_selector_status(&self) -> Status {
+        self.empty_selector_status
+    }
+
+    pub fn leaf_budget(&self) -> Option<u32> {
+        self.leaf_budget
+    }
+
+    /// Asks the tick engine to unwind the current tick without ticking further nodes.
+    pub fn request_abort(&mut self) {
+        self.abort_requested = true;
+    }
+
+    pub fn abort_requested(&self) -> bool {
+        self.abort_requested
+    }
+
+    /// Number of `SubtreeRef` nodes currently being ticked through.
+    pub(crate) fn subtree_depth_mut(&mut self) -> &mut u32 {
+        &mut self.subtree_depth
+    }
+
+    /// Splits the borrow so scoring can read the blackboard while drawing from the RNG.
+    pub(crate) fn blackboard_and_rng(&mut self) -> (&B, Option<&mut dyn RngCore>) {
+        let rng = self.rng.as_mut().map(|rng| &mut **rng as &mut dyn RngCore);
+        (self.blackboard, rng)
+    }
+
+    pub(crate) fn take_scratch(&mut self) -> Option<&'a mut TickScratch> {
+        self.scratch.take()
+    }
+
+    pub(crate) fn restore_scratch(&mut self, scratch: Option<&'a mut TickScratch>) {
+        self.scratch = scratch;
+    }
+
+    /// Spends one unit of the leaf budget, returning `false` once it is exhausted.
+    pub(crate) fn consume_leaf(&mut self) -> bool {
+        match self.leaf_budget.as_mut() {
+            Some(0) => false,
+            Some(remaining) => {
+                *remaining -= 1;
+                true
+            }
+            None => true,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -99,6 +215,36 @@ mod tests {
         assert_eq!(ctx.blackboard().get_int(1), Some(7));
     }
 
+    #[test]
+    fn context_leaf_budget_exhausts() {
+        let mut bb = Blackboard::new();
+        let mut ctx = Context::new(0, 1, &mut bb, None).with_leaf_budget(Some(2));
+        assert!(ctx.consume_leaf());
+        assert!(ctx.consume_leaf());
+        assert!(!ctx.consume_leaf());
+        assert_eq!(ctx.leaf_budget(), Some(0));
+    }
+
+    #[test]
+    fn context_request_abort() {
+        let mut bb = Blackboard::new();
+        let mut ctx = Context::new(0, 1, &mut bb, None);
+        assert!(!ctx.abort_requested());
+        ctx.request_abort();
+        assert!(ctx.abort_requested());
+    }
+
+    #[test]
+    fn context_user_data_downcast() {
+        let mut bb = Blackboard::new();
+        let mut counter = 3u32;
+        let mut ctx = Context::new(0, 1, &mut bb, None).with_user_data(Some(&mut counter));
+        assert!(ctx.has_user_data());
+        assert!(ctx.user_data::<i64>().is_none());
+        *ctx.user_data::<u32>().unwrap() += 1;
+        assert_eq!(counter, 4);
+    }
+
     #[test]
     fn context_rng_access() {
         let mut bb = Blackboard::new();
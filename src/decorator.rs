@@ -1,3 +1,5 @@
+use crate::status::Status;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Decorator {
     Inverter,
@@ -10,11 +12,39 @@ pub enum Decorator {
     Timeout(u32),
     ForceSuccess,
     ForceFailure,
+    WaitUntil(u32),
+    RepeatTolerant {
+        iterations: u32,
+        max_failures: u32,
+    },
+    Chance(u32),
+    InterruptIf(u32),
+    /// Holds a finished child's result for one tick, reporting Running in between.
+    Yield,
+    /// Restarts the child whenever it finishes, so it only ever reports Running.
+    KeepRunning,
+    /// Lets the child complete at most N times, then fails without ticking it; ancestor resets
+    /// don't restore the allowance, only a full tree reset does.
+    Limit(u32),
+    /// Reports Running for N ticks each activation before ticking the child.
+    Delay(u32),
+    /// Ticks the child until it first succeeds, then succeeds without ticking it until a full
+    /// tree reset.
+    Once,
+    /// Replaces the child's Success and Failure; Running passes through.
+    Remap {
+        on_success: Status,
+        on_failure: Status,
+    },
+    /// Fails and resets a composite child once the same one of its children has been Running
+    /// for more than N consecutive ticks.
+    RunningTimeout(u32),
 }
 
 #[cfg(test)]
 mod tests {
     use super::Decorator;
+    use crate::status::Status;
 
     #[test]
     fn decorator_clone() {
@@ -29,6 +59,23 @@ mod tests {
             Decorator::Timeout(5),
             Decorator::ForceSuccess,
             Decorator::ForceFailure,
+            Decorator::WaitUntil(6),
+            Decorator::RepeatTolerant {
+                iterations: 7,
+                max_failures: 2,
+            },
+            Decorator::Chance(500),
+            Decorator::InterruptIf(8),
+            Decorator::Yield,
+            Decorator::KeepRunning,
+            Decorator::Limit(9),
+            Decorator::Delay(10),
+            Decorator::Once,
+            Decorator::Remap {
+                on_success: Status::Failure,
+                on_failure: Status::Success,
+            },
+            Decorator::RunningTimeout(11),
         ];
 
         for d in all {
@@ -2,9 +2,32 @@
 pub enum TreeError {
     EmptyComposite,
     MaxDepthExceeded(usize),
-    WeightCountMismatch { children: usize, weights: usize },
-    UtilityIdCountMismatch { children: usize, ids: usize },
+    WeightCountMismatch {
+        children: usize,
+        weights: usize,
+    },
+    UtilityIdCountMismatch {
+        children: usize,
+        ids: usize,
+    },
+    InterruptCountMismatch {
+        children: usize,
+        interrupts: usize,
+    },
+    /// A `RequireN` parallel needs more successes than it has children.
+    ParallelThresholdTooHigh {
+        required: usize,
+        children: usize,
+    },
+    /// A composite has more children than [`MAX_CHILDREN`](crate::tick::MAX_CHILDREN).
+    TooManyChildren(usize),
     UnbalancedBuilder(usize),
+    /// A saved [`TreeState`](crate::tree::TreeState) or an [`AgentState`](crate::AgentState)
+    /// doesn't match the tree's node count.
+    StateSizeMismatch {
+        expected: usize,
+        actual: usize,
+    },
 }
 
 #[cfg(test)]
@@ -20,8 +43,24 @@ mod tests {
                 children: 2,
                 weights: 1,
             },
-            TreeError::UtilityIdCountMismatch { children: 3, ids: 4 },
+            TreeError::UtilityIdCountMismatch {
+                children: 3,
+                ids: 4,
+            },
+            TreeError::InterruptCountMismatch {
+                children: 2,
+                interrupts: 3,
+            },
+            TreeError::ParallelThresholdTooHigh {
+                required: 5,
+                children: 2,
+            },
+            TreeError::TooManyChildren(70_000),
             TreeError::UnbalancedBuilder(1),
+            TreeError::StateSizeMismatch {
+                expected: 3,
+                actual: 2,
+            },
         ];
 
         assert!(matches!(all[0], TreeError::EmptyComposite));
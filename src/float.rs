@@ -15,12 +15,17 @@ pub trait Float:
     fn one() -> Self;
     fn half() -> Self;
     fn two() -> Self;
+    /// Smallest magnitude treated as non-zero when guarding divisions.
+    fn epsilon() -> Self;
     fn from_f32(v: f32) -> Self;
     fn to_f32(self) -> f32;
     fn sqrt(self) -> Self;
     fn exp(self) -> Self;
     fn ln(self) -> Self;
     fn abs(self) -> Self;
+    fn floor(self) -> Self;
+    /// False for NaN and infinities; fixed-point values are always finite.
+    fn is_finite(self) -> bool;
     fn min(self, other: Self) -> Self;
     fn max(self, other: Self) -> Self;
     fn powf(self, exp: Self) -> Self;
@@ -51,6 +56,10 @@ impl Float for f32 {
         2.0
     }
 
+    fn epsilon() -> Self {
+        1.0e-6
+    }
+
     fn from_f32(v: f32) -> Self {
         v
     }
@@ -75,12 +84,28 @@ impl Float for f32 {
         libm::fabsf(self)
     }
 
+    fn floor(self) -> Self {
+        libm::floorf(self)
+    }
+
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+
     fn min(self, other: Self) -> Self {
-        if self < other { self } else { other }
+        if self < other {
+            self
+        } else {
+            other
+        }
     }
 
     fn max(self, other: Self) -> Self {
-        if self > other { self } else { other }
+        if self > other {
+            self
+        } else {
+            other
+        }
     }
 
     fn powf(self, exp: Self) -> Self {
@@ -105,6 +130,10 @@ impl Float for f64 {
         2.0
     }
 
+    fn epsilon() -> Self {
+        1.0e-12
+    }
+
     fn from_f32(v: f32) -> Self {
         v as f64
     }
@@ -129,12 +158,28 @@ impl Float for f64 {
         libm::fabs(self)
     }
 
+    fn floor(self) -> Self {
+        libm::floor(self)
+    }
+
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+
     fn min(self, other: Self) -> Self {
-        if self < other { self } else { other }
+        if self < other {
+            self
+        } else {
+            other
+        }
     }
 
     fn max(self, other: Self) -> Self {
-        if self > other { self } else { other }
+        if self > other {
+            self
+        } else {
+            other
+        }
     }
 
     fn powf(self, exp: Self) -> Self {
@@ -142,9 +187,309 @@ impl Float for f64 {
     }
 }
 
+/// Half precision for memory-bound curve tables; transcendental functions round-trip through
+/// `f32`.
+#[cfg(feature = "half")]
+impl Float for half::f16 {
+    fn zero() -> Self {
+        half::f16::ZERO
+    }
+
+    fn one() -> Self {
+        half::f16::ONE
+    }
+
+    fn half() -> Self {
+        half::f16::from_f32(0.5)
+    }
+
+    fn two() -> Self {
+        half::f16::from_f32(2.0)
+    }
+
+    fn epsilon() -> Self {
+        half::f16::EPSILON
+    }
+
+    fn from_f32(v: f32) -> Self {
+        half::f16::from_f32(v)
+    }
+
+    fn to_f32(self) -> f32 {
+        half::f16::to_f32(self)
+    }
+
+    fn sqrt(self) -> Self {
+        Self::from_f32(libm::sqrtf(self.to_f32()))
+    }
+
+    fn exp(self) -> Self {
+        Self::from_f32(libm::expf(self.to_f32()))
+    }
+
+    fn ln(self) -> Self {
+        Self::from_f32(libm::logf(self.to_f32()))
+    }
+
+    fn abs(self) -> Self {
+        Self::from_bits(self.to_bits() & 0x7fff)
+    }
+
+    fn floor(self) -> Self {
+        Self::from_f32(libm::floorf(self.to_f32()))
+    }
+
+    fn is_finite(self) -> bool {
+        half::f16::is_finite(self)
+    }
+
+    fn min(self, other: Self) -> Self {
+        if self < other {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if self > other {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn powf(self, exp: Self) -> Self {
+        Self::from_f32(libm::powf(self.to_f32(), exp.to_f32()))
+    }
+}
+
+/// Q16.16 fixed-point number for bit-deterministic math across platforms.
+///
+/// Arithmetic saturates instead of overflowing, and the transcendental functions
+/// use integer-only approximations, so results never depend on the host FPU.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed(i32);
+
+const FRAC_BITS: u32 = 16;
+const ONE_Q32: i64 = 1 << 32;
+const LN2_Q32: i64 = 2_977_044_472;
+
+impl Fixed {
+    pub const ZERO: Fixed = Fixed(0);
+    pub const ONE: Fixed = Fixed(1 << FRAC_BITS);
+    pub const MAX: Fixed = Fixed(i32::MAX);
+    pub const MIN: Fixed = Fixed(i32::MIN);
+
+    pub const fn from_raw(raw: i32) -> Self {
+        Fixed(raw)
+    }
+
+    pub const fn raw(self) -> i32 {
+        self.0
+    }
+
+    pub const fn from_int(v: i16) -> Self {
+        Fixed((v as i32) << FRAC_BITS)
+    }
+
+    fn saturate(v: i64) -> Self {
+        Fixed(v.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+    }
+
+    /// Narrows a Q32.32 intermediate back to Q16.16, rounding to nearest.
+    fn from_q32(v: i64) -> Self {
+        Self::saturate((v + (1 << (FRAC_BITS - 1))) >> FRAC_BITS)
+    }
+}
+
+impl core::ops::Add for Fixed {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Fixed(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl core::ops::Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Fixed(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl core::ops::Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::saturate((self.0 as i64 * rhs.0 as i64) >> FRAC_BITS)
+    }
+}
+
+impl core::ops::Div for Fixed {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        if rhs.0 == 0 {
+            return match self.0 {
+                0 => Fixed::ZERO,
+                v if v > 0 => Fixed::MAX,
+                _ => Fixed::MIN,
+            };
+        }
+        Self::saturate(((self.0 as i64) << FRAC_BITS) / rhs.0 as i64)
+    }
+}
+
+impl core::ops::Neg for Fixed {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fixed(self.0.saturating_neg())
+    }
+}
+
+impl Float for Fixed {
+    fn zero() -> Self {
+        Fixed::ZERO
+    }
+
+    fn one() -> Self {
+        Fixed::ONE
+    }
+
+    fn half() -> Self {
+        Fixed(1 << (FRAC_BITS - 1))
+    }
+
+    fn two() -> Self {
+        Fixed(2 << FRAC_BITS)
+    }
+
+    fn epsilon() -> Self {
+        Fixed(1)
+    }
+
+    fn from_f32(v: f32) -> Self {
+        Fixed(libm::roundf(v * (1u32 << FRAC_BITS) as f32) as i32)
+    }
+
+    fn to_f32(self) -> f32 {
+        self.0 as f32 / (1u32 << FRAC_BITS) as f32
+    }
+
+    fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Fixed::ZERO;
+        }
+        let target = (self.0 as u64) << FRAC_BITS;
+        let mut root = 0u64;
+        let mut bit = 1u64 << 62;
+        while bit > target {
+            bit >>= 2;
+        }
+        let mut rem = target;
+        while bit != 0 {
+            if rem >= root + bit {
+                rem -= root + bit;
+                root = (root >> 1) + bit;
+            } else {
+                root >>= 1;
+            }
+            bit >>= 2;
+        }
+        Fixed(root as i32)
+    }
+
+    fn exp(self) -> Self {
+        // exp(x) = 2^k * exp(r) with r in [0, ln 2), evaluated as a Taylor series in Q32.32.
+        let x = (self.0 as i64) << FRAC_BITS;
+        let k = x.div_euclid(LN2_Q32);
+        if k >= 15 {
+            return Fixed::MAX;
+        }
+        if k < -(FRAC_BITS as i64) - 1 {
+            return Fixed::ZERO;
+        }
+        let r = x - k * LN2_Q32;
+        let mut term = ONE_Q32;
+        let mut sum = ONE_Q32;
+        for n in 1..=12 {
+            term = ((term as i128 * r as i128) >> 32) as i64 / n;
+            sum += term;
+        }
+        if k >= 0 {
+            Self::from_q32(sum << k)
+        } else {
+            Self::from_q32(sum >> -k)
+        }
+    }
+
+    fn ln(self) -> Self {
+        if self.0 <= 0 {
+            return Fixed::MIN;
+        }
+        // ln(x) = k * ln 2 + ln(m) with m in [1, 2), using ln(m) = 2 * atanh((m - 1) / (m + 1)).
+        let msb = 31 - self.0.leading_zeros() as i64;
+        let k = msb - FRAC_BITS as i64;
+        let m = ((self.0 as i64) << 32) >> msb;
+        let z = (((m - ONE_Q32) as i128) << 32) / (m + ONE_Q32) as i128;
+        let z = z as i64;
+        let z2 = ((z as i128 * z as i128) >> 32) as i64;
+        let mut term = z;
+        let mut sum = z;
+        for n in (3..=15).step_by(2) {
+            term = ((term as i128 * z2 as i128) >> 32) as i64;
+            sum += term / n;
+        }
+        Self::from_q32(2 * sum + k * LN2_Q32)
+    }
+
+    fn abs(self) -> Self {
+        Fixed(self.0.saturating_abs())
+    }
+
+    fn floor(self) -> Self {
+        Fixed(self.0 & !((1 << FRAC_BITS) - 1))
+    }
+
+    fn is_finite(self) -> bool {
+        true
+    }
+
+    fn min(self, other: Self) -> Self {
+        if self < other {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if self > other {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn powf(self, exp: Self) -> Self {
+        if self.0 <= 0 {
+            return match exp.0 {
+                0 => Fixed::ONE,
+                e if e > 0 || self.0 < 0 => Fixed::ZERO,
+                _ => Fixed::MAX,
+            };
+        }
+        (exp * self.ln()).exp()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Float;
+    use super::{Fixed, Float};
+    use crate::utility::curve::ResponseCurve;
 
     fn approx_eq(left: f32, right: f32) {
         assert!((left - right).abs() < 1.0e-4, "{left} != {right}");
@@ -162,5 +507,147 @@ mod tests {
         approx_eq(4.0f32.sqrt(), 2.0);
         approx_eq(0.0f32.exp(), 1.0);
         approx_eq(1.0f32.ln(), 0.0);
+        assert!(Float::is_finite(1.0f32));
+        assert!(!Float::is_finite(f32::NAN));
+        assert!(!Float::is_finite(f64::INFINITY));
+        assert!(Fixed::MAX.is_finite());
+    }
+
+    #[test]
+    fn fixed_arithmetic() {
+        let a = Fixed::from_f32(1.5);
+        let b = Fixed::from_f32(-0.25);
+        approx_eq((a + b).to_f32(), 1.25);
+        approx_eq((a * b).to_f32(), -0.375);
+        approx_eq((a / b).to_f32(), -6.0);
+        assert_eq!(Fixed::ONE / Fixed::ZERO, Fixed::MAX);
+        assert_eq!(Fixed::MAX + Fixed::ONE, Fixed::MAX);
+    }
+
+    #[test]
+    fn fixed_math_matches_f32() {
+        for v in [0.01f32, 0.25, 0.5, 1.0, 2.0, 7.5, 100.0] {
+            let f = Fixed::from_f32(v);
+            approx_eq(f.sqrt().to_f32(), f.to_f32().sqrt());
+            approx_eq(f.ln().to_f32(), f.to_f32().ln());
+        }
+        for v in [-8.0f32, -1.0, 0.0, 0.3, 1.0, 4.0, 9.0] {
+            let expected = v.exp();
+            let got = Fixed::from_f32(v).exp().to_f32();
+            assert!(
+                (got - expected).abs() <= expected * 1.0e-3 + 1.0e-4,
+                "{got} != {expected}"
+            );
+        }
+        approx_eq(Fixed::from_f32(0.25).powf(Fixed::half()).to_f32(), 0.5);
+        approx_eq(Fixed::from_f32(-1.25).floor().to_f32(), -2.0);
+        approx_eq(Fixed::from_f32(2.75).floor().to_f32(), 2.0);
+        assert_eq!(Fixed::ZERO.powf(Fixed::two()), Fixed::ZERO);
+    }
+
+    fn assert_curves_match(fixed: ResponseCurve<Fixed>, float: ResponseCurve<f32>) {
+        for i in 0..=20 {
+            let x = i as f32 / 20.0;
+            let got = fixed.evaluate(Fixed::from_f32(x)).to_f32();
+            let expected = float.evaluate(x);
+            assert!(
+                (got - expected).abs() < 1.0e-3,
+                "x={x}: {got} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn fixed_curve_linear() {
+        assert_curves_match(
+            ResponseCurve::Linear {
+                slope: Fixed::from_f32(-0.8),
+                offset: Fixed::from_f32(0.9),
+            },
+            ResponseCurve::Linear {
+                slope: -0.8,
+                offset: 0.9,
+            },
+        );
+    }
+
+    #[test]
+    fn fixed_curve_logistic() {
+        assert_curves_match(
+            ResponseCurve::Logistic {
+                midpoint: Fixed::from_f32(0.4),
+                steepness: Fixed::from_f32(10.0),
+            },
+            ResponseCurve::Logistic {
+                midpoint: 0.4,
+                steepness: 10.0,
+            },
+        );
+    }
+
+    #[test]
+    fn fixed_curve_polynomial() {
+        assert_curves_match(
+            ResponseCurve::Polynomial {
+                exponent: Fixed::from_f32(2.5),
+                offset: Fixed::from_f32(0.1),
+            },
+            ResponseCurve::Polynomial {
+                exponent: 2.5,
+                offset: 0.1,
+            },
+        );
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn half_curves_match_f32() {
+        use half::f16;
+
+        let pairs = [
+            (
+                ResponseCurve::Linear {
+                    slope: f16::from_f32(-0.8),
+                    offset: f16::from_f32(0.9),
+                },
+                ResponseCurve::Linear {
+                    slope: -0.8,
+                    offset: 0.9,
+                },
+            ),
+            (
+                ResponseCurve::Logistic {
+                    midpoint: f16::from_f32(0.4),
+                    steepness: f16::from_f32(10.0),
+                },
+                ResponseCurve::Logistic {
+                    midpoint: 0.4,
+                    steepness: 10.0,
+                },
+            ),
+            (
+                ResponseCurve::Polynomial {
+                    exponent: f16::from_f32(2.5),
+                    offset: f16::from_f32(0.1),
+                },
+                ResponseCurve::Polynomial {
+                    exponent: 2.5,
+                    offset: 0.1,
+                },
+            ),
+        ];
+        for (half, float) in pairs {
+            for i in 0..=20 {
+                let x = i as f32 / 20.0;
+                let got = half.evaluate(f16::from_f32(x)).to_f32();
+                let expected = float.evaluate(x);
+                assert!(
+                    (got - expected).abs() < 1.0e-2,
+                    "x={x}: {got} != {expected}"
+                );
+            }
+        }
+        approx_eq(Float::sqrt(f16::from_f32(4.0)).to_f32(), 2.0);
+        approx_eq(Float::abs(f16::from_f32(-1.5)).to_f32(), 1.5);
     }
 }
@@ -1,9 +1,348 @@
-use crate::{Context, Status};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cell::RefCell;
 
-pub trait ActionHandler<A> {
-    fn execute(&mut self, action: &A, ctx: &mut Context) -> Status;
+use crate::{Blackboard, BlackboardStore, Context, Status};
+
+/// Runs actions; `B` is the blackboard store of the trees it drives.
+pub trait ActionHandler<A, B = Blackboard> {
+    fn execute(&mut self, action: &A, ctx: &mut Context<B>) -> Status;
+
+    /// Called when a Running action is abandoned because its subtree was reset; never called
+    /// for an action whose `execute` hasn't run, e.g. one still deferred by the leaf budget.
+    fn on_abort(&mut self, _action: &A, _ctx: &mut Context<B>) {}
+
+    /// Whether this handler claims `action`; used by [`ChainedActionHandler`] for routing.
+    fn handles(&self, _action: &A) -> bool {
+        true
+    }
+}
+
+pub trait ConditionHandler<C, B = Blackboard> {
+    fn check(&self, condition: &C, ctx: &Context<B>) -> bool;
+}
+
+/// Adapts a closure into an [`ActionHandler`].
+pub struct ClosureActionHandler<F> {
+    f: F,
+}
+
+impl<F> ClosureActionHandler<F> {
+    pub fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<A, B, F> ActionHandler<A, B> for ClosureActionHandler<F>
+where
+    F: FnMut(&A, &mut Context<B>) -> Status,
+{
+    fn execute(&mut self, action: &A, ctx: &mut Context<B>) -> Status {
+        (self.f)(action, ctx)
+    }
+}
+
+/// Adapts a closure into a [`ConditionHandler`]; `check` takes `&self`, so the closure sits
+/// behind a `RefCell` to allow `FnMut`.
+pub struct ClosureConditionHandler<F> {
+    f: RefCell<F>,
+}
+
+impl<F> ClosureConditionHandler<F> {
+    pub fn new(f: F) -> Self {
+        Self { f: RefCell::new(f) }
+    }
+}
+
+impl<C, B, F> ConditionHandler<C, B> for ClosureConditionHandler<F>
+where
+    F: FnMut(&C, &Context<B>) -> bool,
+{
+    fn check(&self, condition: &C, ctx: &Context<B>) -> bool {
+        (self.f.borrow_mut())(condition, ctx)
+    }
+}
+
+/// Memoizes an inner handler's results by condition id until [`begin_tick`] clears the cache,
+/// so a condition shared across branches is checked at most once per tick.
+///
+/// [`begin_tick`]: CachingConditionHandler::begin_tick
+pub struct CachingConditionHandler<C: Ord, H> {
+    inner: H,
+    cache: RefCell<BTreeMap<C, bool>>,
+}
+
+impl<C: Ord, H> CachingConditionHandler<C, H> {
+    pub fn new(inner: H) -> Self {
+        Self {
+            inner,
+            cache: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Forgets every cached result; call once before each tick.
+    pub fn begin_tick(&mut self) {
+        self.cache.get_mut().clear();
+    }
+
+    pub fn inner(&self) -> &H {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> H {
+        self.inner
+    }
+}
+
+impl<C, B, H> ConditionHandler<C, B> for CachingConditionHandler<C, H>
+where
+    C: Ord + Clone,
+    H: ConditionHandler<C, B>,
+{
+    fn check(&self, condition: &C, ctx: &Context<B>) -> bool {
+        if let Some(&cached) = self.cache.borrow().get(condition) {
+            return cached;
+        }
+        let result = self.inner.check(condition, ctx);
+        self.cache.borrow_mut().insert(condition.clone(), result);
+        result
+    }
+}
+
+type TableEntry<B> = Box<dyn FnMut(&mut Context<B>) -> Status>;
+
+/// Dispatches actions to closures registered by id; unregistered ids fail.
+pub struct TableActionHandler<A: Ord, B = Blackboard> {
+    table: BTreeMap<A, TableEntry<B>>,
+}
+
+impl<A: Ord, B> TableActionHandler<A, B> {
+    pub fn new() -> Self {
+        Self {
+            table: BTreeMap::new(),
+        }
+    }
+
+    pub fn register<F>(mut self, id: A, f: F) -> Self
+    where
+        F: FnMut(&mut Context<B>) -> Status + 'static,
+    {
+        self.table.insert(id, Box::new(f));
+        self
+    }
+}
+
+impl<A: Ord, B> Default for TableActionHandler<A, B> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-pub trait ConditionHandler<C> {
-    fn check(&self, condition: &C, ctx: &Context) -> bool;
+impl<A: Ord, B: BlackboardStore> ActionHandler<A, B> for TableActionHandler<A, B> {
+    fn execute(&mut self, action: &A, ctx: &mut Context<B>) -> Status {
+        match self.table.get_mut(action) {
+            Some(f) => f(ctx),
+            None => Status::Failure,
+        }
+    }
+
+    fn handles(&self, action: &A) -> bool {
+        self.table.contains_key(action)
+    }
+}
+
+/// Routes each action to the first handler that claims it; unclaimed actions fail.
+pub struct ChainedActionHandler<A, B = Blackboard> {
+    handlers: Vec<Box<dyn ActionHandler<A, B>>>,
+}
+
+impl<A, B> ChainedActionHandler<A, B> {
+    pub fn new() -> Self {
+        Self {
+            handlers: Vec::new(),
+        }
+    }
+
+    pub fn with<H>(mut self, handler: H) -> Self
+    where
+        H: ActionHandler<A, B> + 'static,
+    {
+        self.handlers.push(Box::new(handler));
+        self
+    }
+
+    fn route(&mut self, action: &A) -> Option<&mut Box<dyn ActionHandler<A, B>>> {
+        self.handlers.iter_mut().find(|h| h.handles(action))
+    }
+}
+
+impl<A, B> Default for ChainedActionHandler<A, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A, B: BlackboardStore> ActionHandler<A, B> for ChainedActionHandler<A, B> {
+    fn execute(&mut self, action: &A, ctx: &mut Context<B>) -> Status {
+        match self.route(action) {
+            Some(handler) => handler.execute(action, ctx),
+            None => Status::Failure,
+        }
+    }
+
+    fn on_abort(&mut self, action: &A, ctx: &mut Context<B>) {
+        if let Some(handler) = self.route(action) {
+            handler.on_abort(action, ctx);
+        }
+    }
+
+    fn handles(&self, action: &A) -> bool {
+        self.handlers.iter().any(|h| h.handles(action))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use core::cell::Cell;
+
+    use super::{
+        CachingConditionHandler, ChainedActionHandler, ClosureActionHandler,
+        ClosureConditionHandler, TableActionHandler,
+    };
+    use crate::{
+        ActionHandler, BehaviorNode, BehaviorTree, Blackboard, ConditionHandler, Context,
+        NoOpObserver, Status,
+    };
+
+    #[test]
+    fn closure_handlers_drive_tree() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::Selector(vec![
+            BehaviorNode::Sequence(vec![BehaviorNode::Condition(5), BehaviorNode::Action(1)]),
+            BehaviorNode::Action(2),
+        ]);
+        let mut tree = BehaviorTree::new(root);
+        let mut calls = Vec::new();
+        let mut checks = 0u32;
+        {
+            let mut actions = ClosureActionHandler::new(|action: &u32, _ctx: &mut Context| {
+                calls.push(*action);
+                Status::Success
+            });
+            let conditions = ClosureConditionHandler::new(|key: &u32, ctx: &Context| {
+                checks += 1;
+                ctx.blackboard().get_bool(*key).unwrap_or(false)
+            });
+            let mut observer = NoOpObserver;
+
+            tree.tick(&mut actions, &conditions, &mut observer);
+            tree.blackboard_mut().set_bool(5, true);
+            tree.tick(&mut actions, &conditions, &mut observer);
+        }
+        assert_eq!(calls, vec![2, 1]);
+        assert_eq!(checks, 2);
+    }
+
+    #[test]
+    fn table_handler_dispatches_by_id() {
+        let mut actions = TableActionHandler::new()
+            .register(1u32, |ctx: &mut Context| {
+                ctx.blackboard_mut().set_int(1, 10);
+                Status::Success
+            })
+            .register(2u32, |_ctx: &mut Context| Status::Running);
+        let mut bb = Blackboard::new();
+        let mut ctx = Context::new(0, 1, &mut bb, None);
+
+        assert_eq!(actions.execute(&1, &mut ctx), Status::Success);
+        assert_eq!(actions.execute(&2, &mut ctx), Status::Running);
+        assert_eq!(actions.execute(&3, &mut ctx), Status::Failure);
+        assert_eq!(bb.get_int(1), Some(10));
+    }
+
+    #[test]
+    fn chained_handler_routes_to_claiming_handler() {
+        struct Only(u32, Status);
+
+        impl ActionHandler<u32> for Only {
+            fn execute(&mut self, _action: &u32, _ctx: &mut Context) -> Status {
+                self.1
+            }
+
+            fn handles(&self, action: &u32) -> bool {
+                *action == self.0
+            }
+        }
+
+        let mut actions = ChainedActionHandler::new()
+            .with(Only(1, Status::Success))
+            .with(Only(2, Status::Running));
+        let mut bb = Blackboard::new();
+        let mut ctx = Context::new(0, 1, &mut bb, None);
+
+        assert_eq!(actions.execute(&1, &mut ctx), Status::Success);
+        assert_eq!(actions.execute(&2, &mut ctx), Status::Running);
+        assert_eq!(actions.execute(&3, &mut ctx), Status::Failure);
+        assert!(!actions.handles(&3));
+    }
+
+    #[test]
+    fn table_and_chained_handlers_drive_array_blackboard_tree() {
+        use crate::{ArrayBlackboard, BlackboardStore, BlackboardValue};
+
+        type Board = ArrayBlackboard<2>;
+        let root: BehaviorNode<u32, u32> =
+            BehaviorNode::Sequence(vec![BehaviorNode::Action(1), BehaviorNode::Action(2)]);
+        let mut tree = BehaviorTree::with_blackboard(root, Board::new());
+        let table = TableActionHandler::new().register(1u32, |ctx: &mut Context<Board>| {
+            ctx.blackboard_mut().set(1, BlackboardValue::Int(10));
+            Status::Success
+        });
+        let mut actions = ChainedActionHandler::new()
+            .with(table)
+            .with(ClosureActionHandler::new(
+                |_: &u32, _: &mut Context<Board>| Status::Running,
+            ));
+        let conditions = ClosureConditionHandler::new(|_: &u32, _: &Context<Board>| true);
+
+        let status = tree
+            .tick(&mut actions, &conditions, &mut NoOpObserver)
+            .status;
+        assert_eq!(status, Status::Running);
+        assert_eq!(tree.blackboard().get(1), Some(BlackboardValue::Int(10)));
+    }
+
+    #[test]
+    fn caching_handler_checks_each_condition_once_per_tick() {
+        struct Counting(Cell<u32>);
+
+        impl ConditionHandler<u32> for Counting {
+            fn check(&self, condition: &u32, _ctx: &Context) -> bool {
+                self.0.set(self.0.get() + 1);
+                *condition == 2
+            }
+        }
+
+        let root: BehaviorNode<u32, u32> = BehaviorNode::Selector(vec![
+            BehaviorNode::Sequence(vec![BehaviorNode::Condition(1), BehaviorNode::Action(1)]),
+            BehaviorNode::Sequence(vec![BehaviorNode::Condition(1), BehaviorNode::Action(2)]),
+            BehaviorNode::Sequence(vec![BehaviorNode::Condition(2), BehaviorNode::Condition(1)]),
+            BehaviorNode::Sequence(vec![BehaviorNode::Condition(2), BehaviorNode::Action(3)]),
+        ]);
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = ClosureActionHandler::new(|_: &u32, _: &mut Context| Status::Success);
+        let mut conditions = CachingConditionHandler::new(Counting(Cell::new(0)));
+        let mut observer = NoOpObserver;
+
+        conditions.begin_tick();
+        tree.tick(&mut actions, &conditions, &mut observer);
+        assert_eq!(conditions.inner().0.get(), 2);
+
+        conditions.begin_tick();
+        tree.tick(&mut actions, &conditions, &mut observer);
+        assert_eq!(conditions.inner().0.get(), 4);
+    }
 }
@@ -13,20 +13,35 @@ pub mod node;
 pub mod observer;
 pub mod parallel;
 pub mod preset;
+pub mod rng;
+pub mod shared;
 pub mod status;
+pub mod subtree;
 pub mod tick;
 pub mod tree;
 pub mod utility;
 
-pub use blackboard::{Blackboard, BlackboardValue};
+pub use blackboard::{
+    ArrayBlackboard, Blackboard, BlackboardStore, BlackboardValue, CompareOp, KeyNamespace,
+    NamespacedBlackboard,
+};
 pub use builder::TreeBuilder;
 pub use config::TreeConfig;
 pub use context::Context;
 pub use decorator::Decorator;
 pub use error::TreeError;
-pub use leaf::{ActionHandler, ConditionHandler};
+pub use leaf::{
+    ActionHandler, CachingConditionHandler, ChainedActionHandler, ClosureActionHandler,
+    ClosureConditionHandler, ConditionHandler, TableActionHandler,
+};
 pub use node::BehaviorNode;
-pub use observer::{NoOpObserver, Observer, ObserverEvent, RecordingObserver};
+pub use observer::{
+    LoggingObserver, NoOpObserver, Observer, ObserverEvent, RecordingObserver, TeeObserver,
+    TimingObserver,
+};
 pub use parallel::ParallelPolicy;
-pub use status::Status;
-pub use tree::BehaviorTree;
+pub use rng::SeededRng;
+pub use shared::{AgentState, SharedTree};
+pub use status::{Status, TickOutcome};
+pub use subtree::{SubtreeLibrary, MAX_SUBTREE_DEPTH};
+pub use tree::{validate_tree, validate_tree_with, BehaviorTree, TreeState};
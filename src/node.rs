@@ -1,17 +1,32 @@
 use alloc::boxed::Box;
 use alloc::vec::Vec;
+use core::mem::discriminant;
 
+use crate::blackboard::{BlackboardValue, CompareOp};
 use crate::decorator::Decorator;
 use crate::parallel::ParallelPolicy;
+use crate::tick::node_children;
+use crate::utility::Reasoner;
 
 /// A node in the behavior tree.
 #[derive(Clone, Debug, PartialEq)]
 pub enum BehaviorNode<A, C> {
     Sequence(Vec<BehaviorNode<A, C>>),
     Selector(Vec<BehaviorNode<A, C>>),
+    ReactiveSelector(Vec<BehaviorNode<A, C>>),
     Parallel {
         policy: ParallelPolicy,
         children: Vec<BehaviorNode<A, C>>,
+        /// Stops ticking further children once the policy outcome is decided.
+        short_circuit: bool,
+    },
+    /// A `Parallel` whose children flagged in `interrupts` don't count toward `policy`; when
+    /// one succeeds, every unflagged child is reset and the parallel keeps running. With
+    /// `RequireWeight`, `weights` lists one entry per unflagged child.
+    InterruptParallel {
+        policy: ParallelPolicy,
+        children: Vec<BehaviorNode<A, C>>,
+        interrupts: Vec<bool>,
     },
     Decorator {
         decorator: Decorator,
@@ -19,18 +34,258 @@ pub enum BehaviorNode<A, C> {
     },
     Action(A),
     Condition(C),
+    BlackboardCondition(u32),
+    /// Succeeds if the blackboard value at `key` satisfies `op` against `value`; fails if the
+    /// key is missing.
+    Compare {
+        key: u32,
+        op: CompareOp,
+        value: i32,
+    },
+    /// Writes `value` under `key`; fails if the blackboard store has no room for it.
+    SetBlackboard {
+        key: u32,
+        value: BlackboardValue,
+    },
+    ClearBlackboard(u32),
     Wait(u32),
+    /// Like `Wait`, but counts milliseconds from `Context::delta_millis`.
+    WaitMillis(u32),
+    /// Succeeds at most once every N ticks of `Context::tick` and fails otherwise.
+    Throttle(u32),
+    /// Waits a duration rolled from `[min, max]` ticks when first entered.
+    WaitRandom {
+        min: u32,
+        max: u32,
+    },
     UtilitySelector {
         children: Vec<BehaviorNode<A, C>>,
         utility_ids: Vec<u32>,
     },
-    RandomSelector(Vec<BehaviorNode<A, C>>),
+    /// Picks a child uniformly.
+    RandomSelector {
+        children: Vec<BehaviorNode<A, C>>,
+        /// Mixed into each RNG draw so nodes sharing an RNG pick independently.
+        seed: Option<u32>,
+    },
+    ShuffledSelector {
+        children: Vec<BehaviorNode<A, C>>,
+        /// Mixed into each RNG draw so nodes sharing an RNG shuffle independently.
+        seed: Option<u32>,
+    },
+    ShuffledSequence {
+        children: Vec<BehaviorNode<A, C>>,
+        /// Mixed into each RNG draw so nodes sharing an RNG shuffle independently.
+        seed: Option<u32>,
+    },
     WeightedSelector {
         children: Vec<BehaviorNode<A, C>>,
         weights: Vec<u32>,
+        /// Mixed into each RNG draw so nodes sharing an RNG pick independently.
+        seed: Option<u32>,
+    },
+    BlackboardWeightedSelector {
+        children: Vec<BehaviorNode<A, C>>,
+        weight_keys: Vec<u32>,
+        /// Mixed into each RNG draw so nodes sharing an RNG pick independently.
+        seed: Option<u32>,
+    },
+    /// Ticks the child indexed by the `action_id` the reasoner selects. The reasoner always
+    /// scores in `f32`, whatever float type the tree ticks `UtilitySelector`s with, since
+    /// `BehaviorNode` isn't generic over the float type; to score in another `F`, hold a
+    /// `Reasoner<F, _>` in an action handler and call its `select` there instead.
+    Reasoner {
+        reasoner: Reasoner<f32, usize>,
+        children: Vec<BehaviorNode<A, C>>,
+    },
+    /// Ticks the subtree registered under this id in the tree's `SubtreeLibrary`.
+    SubtreeRef(u32),
+    /// Ticks `primary` until it completes; after `max_ticks` ticks of Running it is aborted and
+    /// `fallback` runs in its place for the rest of the activation.
+    TimedSelector {
+        max_ticks: u32,
+        primary: Box<BehaviorNode<A, C>>,
+        fallback: Box<BehaviorNode<A, C>>,
     },
 }
 
+impl<A, C> BehaviorNode<A, C> {
+    /// Compares topology (node kinds, decorator kinds, weights, utility ids and child counts)
+    /// while ignoring action and condition payloads.
+    pub fn same_shape(&self, other: &BehaviorNode<A, C>) -> bool {
+        if discriminant(self) != discriminant(other) {
+            return false;
+        }
+
+        let params_match = match (self, other) {
+            (
+                BehaviorNode::Decorator { decorator: a, .. },
+                BehaviorNode::Decorator { decorator: b, .. },
+            ) => discriminant(a) == discriminant(b),
+            (
+                BehaviorNode::WeightedSelector { weights: a, .. },
+                BehaviorNode::WeightedSelector { weights: b, .. },
+            ) => a == b,
+            (
+                BehaviorNode::UtilitySelector { utility_ids: a, .. },
+                BehaviorNode::UtilitySelector { utility_ids: b, .. },
+            ) => a == b,
+            (
+                BehaviorNode::BlackboardWeightedSelector { weight_keys: a, .. },
+                BehaviorNode::BlackboardWeightedSelector { weight_keys: b, .. },
+            ) => a == b,
+            (
+                BehaviorNode::InterruptParallel { interrupts: a, .. },
+                BehaviorNode::InterruptParallel { interrupts: b, .. },
+            ) => a == b,
+            (BehaviorNode::SubtreeRef(a), BehaviorNode::SubtreeRef(b)) => a == b,
+            _ => true,
+        };
+
+        let (ours, theirs) = (node_children(self), node_children(other));
+        params_match && ours.len() == theirs.len() && ours.zip(theirs).all(|(a, b)| a.same_shape(b))
+    }
+
+    /// Chance of a `WeightedSelector` picking each child; `None` for other nodes or when every
+    /// weight is zero.
+    pub fn branch_probabilities(&self) -> Option<Vec<f32>> {
+        let BehaviorNode::WeightedSelector { weights, .. } = self else {
+            return None;
+        };
+        let total: u64 = weights.iter().map(|w| u64::from(*w)).sum();
+        if total == 0 {
+            return None;
+        }
+        Some(
+            weights
+                .iter()
+                .map(|w| (f64::from(*w) / total as f64) as f32)
+                .collect(),
+        )
+    }
+
+    /// Upper bound on the ticks this subtree needs to complete, treating actions and
+    /// conditions as instant; `None` for unbounded constructs such as `Repeat(u32::MAX)`,
+    /// `UntilSuccess`, `WaitUntil`, interrupt parallels and subtree references.
+    pub fn max_ticks(&self) -> Option<u32> {
+        fn sum<A, C>(children: &[BehaviorNode<A, C>]) -> Option<u32> {
+            children
+                .iter()
+                .try_fold(0u32, |total, child| total.checked_add(child.max_ticks()?))
+        }
+
+        fn longest<A, C>(children: &[BehaviorNode<A, C>]) -> Option<u32> {
+            children
+                .iter()
+                .try_fold(0u32, |longest, child| Some(longest.max(child.max_ticks()?)))
+        }
+
+        // Repeating decorators start at most one pass per tick, even over instant children.
+        fn repeated(child: Option<u32>, times: u32) -> Option<u32> {
+            if times == u32::MAX {
+                None
+            } else {
+                child?.max(1).checked_mul(times)
+            }
+        }
+
+        match self {
+            BehaviorNode::Sequence(children)
+            | BehaviorNode::ShuffledSequence { children, .. }
+            | BehaviorNode::Selector(children)
+            | BehaviorNode::ReactiveSelector(children)
+            | BehaviorNode::ShuffledSelector { children, .. } => sum(children),
+            BehaviorNode::RandomSelector { children, .. }
+            | BehaviorNode::Parallel { children, .. }
+            | BehaviorNode::UtilitySelector { children, .. }
+            | BehaviorNode::WeightedSelector { children, .. }
+            | BehaviorNode::BlackboardWeightedSelector { children, .. }
+            | BehaviorNode::Reasoner { children, .. } => longest(children),
+            BehaviorNode::InterruptParallel {
+                children,
+                interrupts,
+                ..
+            } => {
+                if interrupts.contains(&true) {
+                    None
+                } else {
+                    longest(children)
+                }
+            }
+            BehaviorNode::TimedSelector {
+                max_ticks,
+                primary,
+                fallback,
+            } => {
+                let primary = primary
+                    .max_ticks()
+                    .map_or(*max_ticks, |t| t.min(*max_ticks));
+                primary.checked_add(fallback.max_ticks()?)
+            }
+            BehaviorNode::Decorator { decorator, child } => {
+                let inner = child.max_ticks();
+                match decorator {
+                    Decorator::Repeat(n) | Decorator::Retry(n) => repeated(inner, *n),
+                    Decorator::RepeatTolerant { iterations, .. } => repeated(inner, *iterations),
+                    Decorator::Timeout(n) => Some(inner.map_or(*n, |t| t.min(*n))),
+                    Decorator::Delay(n) => inner?.checked_add(*n),
+                    Decorator::Yield => inner?.checked_add(1),
+                    Decorator::UntilSuccess
+                    | Decorator::UntilFail
+                    | Decorator::KeepRunning
+                    | Decorator::WaitUntil(_) => None,
+                    Decorator::Inverter
+                    | Decorator::Cooldown(_)
+                    | Decorator::Guard(_)
+                    | Decorator::ForceSuccess
+                    | Decorator::ForceFailure
+                    | Decorator::Chance(_)
+                    | Decorator::InterruptIf(_)
+                    | Decorator::Limit(_)
+                    | Decorator::Once
+                    | Decorator::Remap { .. }
+                    | Decorator::RunningTimeout(_) => inner,
+                }
+            }
+            BehaviorNode::Wait(ticks) => Some(*ticks),
+            BehaviorNode::WaitRandom { min, max } => Some((*min).max(*max)),
+            BehaviorNode::WaitMillis(_) | BehaviorNode::SubtreeRef(_) => None,
+            BehaviorNode::Action(_)
+            | BehaviorNode::Condition(_)
+            | BehaviorNode::BlackboardCondition(_)
+            | BehaviorNode::Compare { .. }
+            | BehaviorNode::SetBlackboard { .. }
+            | BehaviorNode::ClearBlackboard(_)
+            | BehaviorNode::Throttle(_) => Some(0),
+        }
+    }
+
+    /// Every action payload in the tree, in pre-order.
+    pub fn collect_actions(&self) -> Vec<&A> {
+        let mut out = Vec::new();
+        self.collect_leaves(&mut out, &mut Vec::new());
+        out
+    }
+
+    /// Every condition payload in the tree, in pre-order.
+    pub fn collect_conditions(&self) -> Vec<&C> {
+        let mut out = Vec::new();
+        self.collect_leaves(&mut Vec::new(), &mut out);
+        out
+    }
+
+    fn collect_leaves<'a>(&'a self, actions: &mut Vec<&'a A>, conditions: &mut Vec<&'a C>) {
+        match self {
+            BehaviorNode::Action(action) => actions.push(action),
+            BehaviorNode::Condition(condition) => conditions.push(condition),
+            _ => {}
+        }
+        for child in node_children(self) {
+            child.collect_leaves(actions, conditions);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::boxed::Box;
@@ -39,6 +294,7 @@ mod tests {
     use super::BehaviorNode;
     use crate::decorator::Decorator;
     use crate::parallel::ParallelPolicy;
+    use crate::preset;
 
     #[derive(Clone, Debug, PartialEq)]
     enum A {
@@ -62,9 +318,114 @@ mod tests {
             BehaviorNode::Parallel {
                 policy: ParallelPolicy::RequireOne,
                 children: vec![BehaviorNode::Action(A::Attack), BehaviorNode::Wait(2)],
+                short_circuit: false,
             },
         ]);
         let cloned = tree.clone();
         assert_eq!(tree, cloned);
     }
+
+    #[test]
+    fn behavior_node_same_shape_ignores_payloads() {
+        let a: BehaviorNode<u32, u32> = BehaviorNode::Sequence(vec![
+            BehaviorNode::Condition(1),
+            BehaviorNode::Decorator {
+                decorator: Decorator::Repeat(2),
+                child: Box::new(BehaviorNode::Action(1)),
+            },
+        ]);
+        let b: BehaviorNode<u32, u32> = BehaviorNode::Sequence(vec![
+            BehaviorNode::Condition(7),
+            BehaviorNode::Decorator {
+                decorator: Decorator::Repeat(5),
+                child: Box::new(BehaviorNode::Action(9)),
+            },
+        ]);
+        assert!(a.same_shape(&b));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn behavior_node_same_shape_detects_topology_changes() {
+        let a: BehaviorNode<u32, u32> =
+            BehaviorNode::Selector(vec![BehaviorNode::Action(1), BehaviorNode::Action(2)]);
+        let fewer: BehaviorNode<u32, u32> = BehaviorNode::Selector(vec![BehaviorNode::Action(1)]);
+        let other_kind: BehaviorNode<u32, u32> =
+            BehaviorNode::Sequence(vec![BehaviorNode::Action(1), BehaviorNode::Action(2)]);
+        assert!(!a.same_shape(&fewer));
+        assert!(!a.same_shape(&other_kind));
+
+        let weighted = |weights| BehaviorNode::<u32, u32>::WeightedSelector {
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            weights,
+            seed: None,
+        };
+        assert!(!weighted(vec![1, 2]).same_shape(&weighted(vec![2, 1])));
+    }
+
+    #[test]
+    fn behavior_node_collects_leaf_payloads_in_pre_order() {
+        let tree = preset::combat_melee();
+        assert_eq!(tree.collect_conditions(), vec![&2, &1, &0]);
+        assert_eq!(tree.collect_actions(), vec![&3, &2, &1, &0]);
+
+        let decorated: BehaviorNode<A, C> = BehaviorNode::Decorator {
+            decorator: Decorator::Inverter,
+            child: Box::new(BehaviorNode::Action(A::Patrol)),
+        };
+        assert_eq!(decorated.collect_actions(), vec![&A::Patrol]);
+        assert!(decorated.collect_conditions().is_empty());
+    }
+
+    #[test]
+    fn behavior_node_branch_probabilities() {
+        let weighted = |weights| BehaviorNode::<u32, u32>::WeightedSelector {
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            weights,
+            seed: None,
+        };
+        assert_eq!(
+            weighted(vec![1, 9]).branch_probabilities(),
+            Some(vec![0.1, 0.9])
+        );
+        assert_eq!(weighted(vec![0, 0]).branch_probabilities(), None);
+        assert_eq!(
+            BehaviorNode::<u32, u32>::Action(1).branch_probabilities(),
+            None
+        );
+    }
+
+    #[test]
+    fn max_ticks_bounds() {
+        assert_eq!(preset::patrol().max_ticks(), None);
+
+        let bounded: BehaviorNode<u32, u32> =
+            BehaviorNode::Sequence(vec![BehaviorNode::Action(1), BehaviorNode::Wait(60)]);
+        assert_eq!(bounded.max_ticks(), Some(60));
+
+        let repeated: BehaviorNode<u32, u32> = BehaviorNode::Decorator {
+            decorator: Decorator::Repeat(3),
+            child: Box::new(BehaviorNode::Parallel {
+                policy: ParallelPolicy::RequireAll,
+                children: vec![BehaviorNode::Wait(5), BehaviorNode::Wait(8)],
+                short_circuit: false,
+            }),
+        };
+        assert_eq!(repeated.max_ticks(), Some(24));
+
+        let repeated_action: BehaviorNode<u32, u32> = BehaviorNode::Decorator {
+            decorator: Decorator::Repeat(3),
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        assert_eq!(repeated_action.max_ticks(), Some(3));
+
+        let timed: BehaviorNode<u32, u32> = BehaviorNode::Decorator {
+            decorator: Decorator::Timeout(10),
+            child: Box::new(BehaviorNode::Decorator {
+                decorator: Decorator::UntilSuccess,
+                child: Box::new(BehaviorNode::Action(1)),
+            }),
+        };
+        assert_eq!(timed.max_ticks(), Some(10));
+    }
 }
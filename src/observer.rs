@@ -1,4 +1,5 @@
 use alloc::vec::Vec;
+use core::fmt::{self, Write};
 
 use crate::{BlackboardValue, Status};
 
@@ -7,6 +8,10 @@ pub trait Observer {
     fn on_exit(&mut self, _node_id: usize, _status: Status) {}
     fn on_blackboard_write(&mut self, _key: u32, _value: BlackboardValue) {}
     fn on_utility_score(&mut self, _action_index: usize, _score: f32) {}
+    /// A looping decorator (`Repeat`, `Retry`, `RepeatTolerant`) finished its `iteration`th pass.
+    fn on_iteration(&mut self, _node_id: usize, _iteration: u32) {}
+    /// A node that was Running was reset because its branch was abandoned.
+    fn on_cancel(&mut self, _node_id: usize) {}
 }
 
 #[derive(Default)]
@@ -25,6 +30,8 @@ pub enum ObserverEvent {
     Exit(usize, Status),
     BlackboardWrite(u32, BlackboardValue),
     UtilityScore(usize, f32),
+    Iteration(usize, u32),
+    Cancel(usize),
 }
 
 impl Observer for RecordingObserver {
@@ -44,13 +51,206 @@ impl Observer for RecordingObserver {
         self.events
             .push(ObserverEvent::UtilityScore(action_index, score));
     }
+
+    fn on_iteration(&mut self, node_id: usize, iteration: u32) {
+        self.events
+            .push(ObserverEvent::Iteration(node_id, iteration));
+    }
+
+    fn on_cancel(&mut self, node_id: usize) {
+        self.events.push(ObserverEvent::Cancel(node_id));
+    }
+}
+
+/// Forwards every callback to both inner observers, `first` then `second`.
+pub struct TeeObserver<A: Observer, B: Observer> {
+    pub first: A,
+    pub second: B,
+}
+
+impl<A: Observer, B: Observer> TeeObserver<A, B> {
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<A: Observer, B: Observer> Observer for TeeObserver<A, B> {
+    fn on_enter(&mut self, node_id: usize) {
+        self.first.on_enter(node_id);
+        self.second.on_enter(node_id);
+    }
+
+    fn on_exit(&mut self, node_id: usize, status: Status) {
+        self.first.on_exit(node_id, status);
+        self.second.on_exit(node_id, status);
+    }
+
+    fn on_blackboard_write(&mut self, key: u32, value: BlackboardValue) {
+        self.first.on_blackboard_write(key, value);
+        self.second.on_blackboard_write(key, value);
+    }
+
+    fn on_utility_score(&mut self, action_index: usize, score: f32) {
+        self.first.on_utility_score(action_index, score);
+        self.second.on_utility_score(action_index, score);
+    }
+
+    fn on_iteration(&mut self, node_id: usize, iteration: u32) {
+        self.first.on_iteration(node_id, iteration);
+        self.second.on_iteration(node_id, iteration);
+    }
+
+    fn on_cancel(&mut self, node_id: usize) {
+        self.first.on_cancel(node_id);
+        self.second.on_cancel(node_id);
+    }
+}
+
+/// Longest line a [`LoggingObserver`] emits; longer lines are truncated.
+pub const LOG_LINE_CAPACITY: usize = 64;
+
+/// Fixed-capacity UTF-8 buffer so log lines are formatted without allocating.
+struct LineBuffer {
+    bytes: [u8; LOG_LINE_CAPACITY],
+    len: usize,
+}
+
+impl LineBuffer {
+    fn new() -> Self {
+        Self {
+            bytes: [0; LOG_LINE_CAPACITY],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or("")
+    }
+}
+
+impl Write for LineBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let room = LOG_LINE_CAPACITY - self.len;
+        let mut take = s.len().min(room);
+        while !s.is_char_boundary(take) {
+            take -= 1;
+        }
+        self.bytes[self.len..self.len + take].copy_from_slice(&s.as_bytes()[..take]);
+        self.len += take;
+        if take < s.len() {
+            Err(fmt::Error)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Formats each callback as a single line (`"enter 7"`, `"exit 7 Success"`) and hands it to
+/// `sink`, e.g. a UART writer.
+pub struct LoggingObserver<W: FnMut(&str)> {
+    sink: W,
+}
+
+impl<W: FnMut(&str)> LoggingObserver<W> {
+    pub fn new(sink: W) -> Self {
+        Self { sink }
+    }
+
+    fn emit(&mut self, args: fmt::Arguments) {
+        let mut line = LineBuffer::new();
+        // Overflow only truncates the line.
+        let _ = line.write_fmt(args);
+        (self.sink)(line.as_str());
+    }
+}
+
+impl<W: FnMut(&str)> Observer for LoggingObserver<W> {
+    fn on_enter(&mut self, node_id: usize) {
+        self.emit(format_args!("enter {}", node_id));
+    }
+
+    fn on_exit(&mut self, node_id: usize, status: Status) {
+        self.emit(format_args!("exit {} {:?}", node_id, status));
+    }
+
+    fn on_blackboard_write(&mut self, key: u32, value: BlackboardValue) {
+        self.emit(format_args!("write {} {:?}", key, value));
+    }
+
+    fn on_utility_score(&mut self, action_index: usize, score: f32) {
+        self.emit(format_args!("score {} {}", action_index, score));
+    }
+
+    fn on_iteration(&mut self, node_id: usize, iteration: u32) {
+        self.emit(format_args!("iteration {} {}", node_id, iteration));
+    }
+
+    fn on_cancel(&mut self, node_id: usize) {
+        self.emit(format_args!("cancel {}", node_id));
+    }
+}
+
+/// Accumulates per-node time, inclusive of children, from a user-supplied monotonic clock.
+pub struct TimingObserver<F: FnMut() -> u64> {
+    clock: F,
+    started: Vec<Option<u64>>,
+    totals: Vec<u64>,
+}
+
+impl<F: FnMut() -> u64> TimingObserver<F> {
+    pub fn new(clock: F) -> Self {
+        Self {
+            clock,
+            started: Vec::new(),
+            totals: Vec::new(),
+        }
+    }
+
+    pub fn total_time(&self, node_id: usize) -> u64 {
+        self.totals.get(node_id).copied().unwrap_or(0)
+    }
+
+    /// Node with the largest accumulated time; `(0, 0)` before anything is timed.
+    pub fn hottest(&self) -> (usize, u64) {
+        self.totals
+            .iter()
+            .copied()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, total)| *total)
+            .unwrap_or((0, 0))
+    }
+}
+
+impl<F: FnMut() -> u64> Observer for TimingObserver<F> {
+    fn on_enter(&mut self, node_id: usize) {
+        if self.started.len() <= node_id {
+            self.started.resize(node_id + 1, None);
+            self.totals.resize(node_id + 1, 0);
+        }
+        self.started[node_id] = Some((self.clock)());
+    }
+
+    fn on_exit(&mut self, node_id: usize, _status: Status) {
+        let now = (self.clock)();
+        if let Some(start) = self.started.get_mut(node_id).and_then(Option::take) {
+            self.totals[node_id] = self.totals[node_id].saturating_add(now.saturating_sub(start));
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::String;
     use alloc::vec;
 
-    use super::{NoOpObserver, Observer, ObserverEvent, RecordingObserver};
+    use core::fmt::Write;
+
+    use super::{
+        LineBuffer, LoggingObserver, NoOpObserver, Observer, ObserverEvent, RecordingObserver,
+        TeeObserver, TimingObserver, LOG_LINE_CAPACITY,
+    };
+    use crate::{BehaviorNode, BehaviorTree, Context};
     use crate::{BlackboardValue, Status};
 
     #[test]
@@ -78,4 +278,106 @@ mod tests {
         observer.on_enter(0);
         observer.on_exit(0, Status::Running);
     }
+
+    #[test]
+    fn observer_logging_formats_lines() {
+        let mut out = String::new();
+        {
+            let mut observer = LoggingObserver::new(|line: &str| {
+                out.push_str(line);
+                out.push('\n');
+            });
+            observer.on_enter(7);
+            observer.on_exit(7, Status::Success);
+            observer.on_blackboard_write(5, BlackboardValue::Int(7));
+            observer.on_utility_score(1, 0.75);
+            observer.on_iteration(2, 3);
+            observer.on_cancel(4);
+        }
+        assert_eq!(
+            out,
+            "enter 7\nexit 7 Success\nwrite 5 Int(7)\nscore 1 0.75\niteration 2 3\ncancel 4\n"
+        );
+    }
+
+    #[test]
+    fn observer_logging_truncates_long_lines() {
+        let mut line = LineBuffer::new();
+        assert!(write!(line, "{:>80}", "é").is_err());
+        assert!(line.as_str().len() <= LOG_LINE_CAPACITY);
+        assert!(line.as_str().chars().all(|c| c == ' '));
+    }
+
+    #[test]
+    fn observer_timing_accumulates_per_node() {
+        let mut now = 0u64;
+        let mut observer = TimingObserver::new(|| {
+            now += 10;
+            now
+        });
+        observer.on_enter(0);
+        observer.on_enter(1);
+        observer.on_exit(1, Status::Success);
+        observer.on_enter(2);
+        observer.on_exit(2, Status::Success);
+        observer.on_exit(0, Status::Success);
+
+        observer.on_enter(1);
+        observer.on_exit(1, Status::Success);
+
+        assert_eq!(observer.total_time(0), 50);
+        assert_eq!(observer.total_time(1), 20);
+        assert_eq!(observer.total_time(2), 10);
+        assert_eq!(observer.total_time(9), 0);
+        assert_eq!(observer.hottest(), (0, 50));
+    }
+
+    #[test]
+    fn observer_tee_forwards_to_both() {
+        #[derive(Default)]
+        struct Counting {
+            enters: usize,
+            exits: usize,
+        }
+
+        impl Observer for Counting {
+            fn on_enter(&mut self, _node_id: usize) {
+                self.enters += 1;
+            }
+
+            fn on_exit(&mut self, _node_id: usize, _status: Status) {
+                self.exits += 1;
+            }
+        }
+
+        struct Succeed;
+
+        impl crate::ActionHandler<u32> for Succeed {
+            fn execute(&mut self, _action: &u32, _ctx: &mut Context) -> Status {
+                Status::Success
+            }
+        }
+
+        impl crate::ConditionHandler<u32> for Succeed {
+            fn check(&self, _condition: &u32, _ctx: &Context) -> bool {
+                true
+            }
+        }
+
+        let root: BehaviorNode<u32, u32> =
+            BehaviorNode::Sequence(vec![BehaviorNode::Condition(1), BehaviorNode::Action(2)]);
+        let mut tree = BehaviorTree::new(root);
+        let mut observer = TeeObserver::new(RecordingObserver::default(), Counting::default());
+        tree.tick(&mut Succeed, &Succeed, &mut observer);
+
+        let recorded = &observer.first.events;
+        let enters = recorded
+            .iter()
+            .filter(|e| matches!(e, ObserverEvent::Enter(_)))
+            .count();
+        assert_eq!(recorded.len(), 6);
+        assert_eq!(enters, 3);
+        assert_eq!(observer.second.enters, 3);
+        assert_eq!(observer.second.exits, 3);
+    }
 }
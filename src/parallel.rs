@@ -1,12 +1,21 @@
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+use alloc::vec::Vec;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ParallelPolicy {
     RequireAll,
     RequireOne,
     RequireN(usize),
+    /// Succeeds once the weights of succeeding children (one per child) sum to `threshold`.
+    RequireWeight {
+        threshold: u32,
+        weights: Vec<u32>,
+    },
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
+
     use super::ParallelPolicy;
 
     #[test]
@@ -17,5 +26,10 @@ mod tests {
         assert!(matches!(a, ParallelPolicy::RequireAll));
         assert!(matches!(b, ParallelPolicy::RequireOne));
         assert!(matches!(c, ParallelPolicy::RequireN(2)));
+        let d = ParallelPolicy::RequireWeight {
+            threshold: 3,
+            weights: vec![1, 2],
+        };
+        assert_eq!(d.clone(), d);
     }
 }
@@ -3,6 +3,8 @@ use alloc::vec;
 
 use crate::{BehaviorNode, Decorator};
 
+pub mod utility;
+
 pub fn patrol() -> BehaviorNode<u32, u32> {
     BehaviorNode::Decorator {
         decorator: Decorator::Repeat(u32::MAX),
@@ -22,6 +24,22 @@ pub fn combat_melee() -> BehaviorNode<u32, u32> {
     ])
 }
 
+/// Ranged attacker: flee when low on health (condition 2 / action 3), back off when an
+/// enemy is too close (condition 4 / action 5), fire when in range (condition 1) with
+/// line of sight (condition 5) via action 2, and otherwise advance (action 1).
+pub fn combat_ranged() -> BehaviorNode<u32, u32> {
+    BehaviorNode::Selector(vec![
+        BehaviorNode::Sequence(vec![BehaviorNode::Condition(2), BehaviorNode::Action(3)]),
+        BehaviorNode::Sequence(vec![BehaviorNode::Condition(4), BehaviorNode::Action(5)]),
+        BehaviorNode::Sequence(vec![
+            BehaviorNode::Condition(1),
+            BehaviorNode::Condition(5),
+            BehaviorNode::Action(2),
+        ]),
+        BehaviorNode::Action(1),
+    ])
+}
+
 pub fn guard_post() -> BehaviorNode<u32, u32> {
     BehaviorNode::Selector(vec![
         BehaviorNode::Sequence(vec![
@@ -43,7 +61,7 @@ pub fn guard_post() -> BehaviorNode<u32, u32> {
 
 #[cfg(test)]
 mod tests {
-    use super::{combat_melee, guard_post, patrol};
+    use super::{combat_melee, combat_ranged, guard_post, patrol};
     use crate::{BehaviorNode, Decorator};
 
     #[test]
@@ -140,4 +158,47 @@ mod tests {
             _ => panic!("expected selector"),
         }
     }
+
+    #[test]
+    fn preset_ranged_priority() {
+        let tree = combat_ranged();
+        match tree {
+            BehaviorNode::Selector(children) => {
+                assert_eq!(children.len(), 4);
+                match &children[0] {
+                    BehaviorNode::Sequence(branch) => {
+                        assert!(matches!(branch[0], BehaviorNode::Condition(2)));
+                        assert!(matches!(branch[1], BehaviorNode::Action(3)));
+                    }
+                    _ => panic!("expected flee branch"),
+                }
+                match &children[1] {
+                    BehaviorNode::Sequence(branch) => {
+                        assert!(matches!(branch[0], BehaviorNode::Condition(4)));
+                        assert!(matches!(branch[1], BehaviorNode::Action(5)));
+                    }
+                    _ => panic!("expected reposition branch"),
+                }
+                assert!(matches!(children[3], BehaviorNode::Action(1)));
+            }
+            _ => panic!("expected selector"),
+        }
+    }
+
+    #[test]
+    fn preset_ranged_fires_with_line_of_sight() {
+        let tree = combat_ranged();
+        match tree {
+            BehaviorNode::Selector(children) => match &children[2] {
+                BehaviorNode::Sequence(branch) => {
+                    assert_eq!(branch.len(), 3);
+                    assert!(matches!(branch[0], BehaviorNode::Condition(1)));
+                    assert!(matches!(branch[1], BehaviorNode::Condition(5)));
+                    assert!(matches!(branch[2], BehaviorNode::Action(2)));
+                }
+                _ => panic!("expected fire branch"),
+            },
+            _ => panic!("expected selector"),
+        }
+    }
 }
@@ -0,0 +1,129 @@
+use alloc::vec;
+
+use crate::utility::{
+    ClampMode, Consideration, Reasoner, ResponseCurve, SelectionMethod, UtilityAction,
+};
+
+/// Blackboard key holding health in `0..=100`.
+pub const HEALTH: u32 = 0;
+/// Blackboard key holding distance to the nearest enemy in `0..=50`.
+pub const ENEMY_DISTANCE: u32 = 1;
+/// Blackboard key holding remaining ammo in `0..=30`.
+pub const AMMO: u32 = 2;
+
+pub const FIGHT: u32 = 0;
+pub const FLEE: u32 = 1;
+pub const HEAL: u32 = 2;
+
+fn consideration(input_key: u32, curve: ResponseCurve<f32>, input_max: f32) -> Consideration<f32> {
+    Consideration {
+        input_key,
+        curve,
+        weight: 1.0,
+        input_min: 0.0,
+        input_max,
+        clamp_mode: ClampMode::Clamp,
+        invert: false,
+        bias: 0.0,
+        clamp_output: false,
+    }
+}
+
+fn rising() -> ResponseCurve<f32> {
+    ResponseCurve::Linear {
+        slope: 1.0,
+        offset: 0.0,
+    }
+}
+
+fn falling() -> ResponseCurve<f32> {
+    ResponseCurve::Linear {
+        slope: -1.0,
+        offset: 1.0,
+    }
+}
+
+/// Fight/flee/heal reasoner over [`HEALTH`], [`ENEMY_DISTANCE`] and [`AMMO`]; action ids
+/// match their indices ([`FIGHT`], [`FLEE`], [`HEAL`]).
+pub fn survival_reasoner() -> Reasoner<f32, u32> {
+    Reasoner {
+        actions: vec![
+            UtilityAction {
+                action_id: FIGHT,
+                considerations: vec![
+                    consideration(HEALTH, rising(), 100.0),
+                    consideration(ENEMY_DISTANCE, falling(), 50.0),
+                    consideration(AMMO, rising(), 30.0),
+                ],
+                weight: 1.0,
+                momentum: 0.05,
+            },
+            UtilityAction {
+                action_id: FLEE,
+                considerations: vec![
+                    consideration(
+                        HEALTH,
+                        ResponseCurve::Logistic {
+                            midpoint: 0.3,
+                            steepness: -12.0,
+                        },
+                        100.0,
+                    ),
+                    consideration(ENEMY_DISTANCE, falling(), 50.0),
+                ],
+                weight: 1.0,
+                momentum: 0.05,
+            },
+            UtilityAction {
+                action_id: HEAL,
+                considerations: vec![
+                    consideration(HEALTH, falling(), 100.0),
+                    consideration(
+                        ENEMY_DISTANCE,
+                        ResponseCurve::Logistic {
+                            midpoint: 0.5,
+                            steepness: 10.0,
+                        },
+                        50.0,
+                    ),
+                ],
+                weight: 1.0,
+                momentum: 0.05,
+            },
+        ],
+        selection_method: SelectionMethod::HighestScore,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{survival_reasoner, AMMO, ENEMY_DISTANCE, FIGHT, FLEE, HEAL, HEALTH};
+    use crate::Blackboard;
+
+    #[test]
+    fn preset_survival_flees_when_hurt_and_close() {
+        let mut bb = Blackboard::new();
+        bb.set_int(HEALTH, 15);
+        bb.set_int(ENEMY_DISTANCE, 5);
+        bb.set_int(AMMO, 0);
+        assert_eq!(survival_reasoner().select(&bb, None, None), FLEE as usize);
+    }
+
+    #[test]
+    fn preset_survival_fights_when_healthy() {
+        let mut bb = Blackboard::new();
+        bb.set_int(HEALTH, 90);
+        bb.set_int(ENEMY_DISTANCE, 10);
+        bb.set_int(AMMO, 25);
+        assert_eq!(survival_reasoner().select(&bb, None, None), FIGHT as usize);
+    }
+
+    #[test]
+    fn preset_survival_heals_when_safe() {
+        let mut bb = Blackboard::new();
+        bb.set_int(HEALTH, 30);
+        bb.set_int(ENEMY_DISTANCE, 50);
+        bb.set_int(AMMO, 10);
+        assert_eq!(survival_reasoner().select(&bb, None, None), HEAL as usize);
+    }
+}
@@ -0,0 +1,323 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use core::any::Any;
+use core::marker::PhantomData;
+
+use rand_core::RngCore;
+
+use crate::float::Float;
+use crate::tick::{assign_ids, tick_root, NodeState, RootTick, TickScratch, TickSettings};
+use crate::{
+    ActionHandler, BehaviorNode, Blackboard, BlackboardStore, ConditionHandler, Observer,
+    SubtreeLibrary, TickOutcome, TreeConfig, TreeError,
+};
+
+/// Per-agent runtime state for a [`SharedTree`], with `B` the agent's [`BlackboardStore`].
+#[derive(Clone, Debug, Default)]
+pub struct AgentState<B = Blackboard> {
+    pub states: Vec<NodeState>,
+    pub blackboard: B,
+    pub tick_count: u64,
+    scratch: TickScratch,
+}
+
+/// One immutable tree definition ticked on behalf of many agents, each owning an
+/// [`AgentState`]; `F` is the float type used for utility scoring.
+pub struct SharedTree<A, C, F = f32> {
+    root: BehaviorNode<A, C>,
+    node_count: usize,
+    library: SubtreeLibrary<A, C>,
+    settings: TickSettings,
+    _float: PhantomData<F>,
+}
+
+impl<A, C> SharedTree<A, C> {
+    pub fn new(root: BehaviorNode<A, C>) -> Self {
+        Self::with_float(root)
+    }
+}
+
+impl<A, C, F: Float> SharedTree<A, C, F> {
+    /// Creates a shared tree that scores utility selectors in `F` instead of `f32`.
+    pub fn with_float(root: BehaviorNode<A, C>) -> Self {
+        let node_count = assign_ids(&root).max(1);
+        Self {
+            root,
+            node_count,
+            library: SubtreeLibrary::new(),
+            settings: TickSettings::default(),
+            _float: PhantomData,
+        }
+    }
+
+    /// Sets the subtrees that `BehaviorNode::SubtreeRef` nodes resolve against.
+    pub fn set_library(&mut self, library: SubtreeLibrary<A, C>) {
+        self.library = library;
+    }
+
+    pub fn library(&self) -> &SubtreeLibrary<A, C> {
+        &self.library
+    }
+
+    /// Replaces the config consulted while ticking, e.g. for empty composite statuses.
+    pub fn set_config(&mut self, config: TreeConfig) {
+        self.settings.config = config;
+    }
+
+    pub fn config(&self) -> &TreeConfig {
+        &self.settings.config
+    }
+
+    /// Caps how many action/condition leaves run per agent tick; `None` removes the cap.
+    pub fn set_leaf_budget(&mut self, budget: Option<u32>) {
+        self.settings.leaf_budget = budget;
+    }
+
+    pub fn leaf_budget(&self) -> Option<u32> {
+        self.settings.leaf_budget
+    }
+
+    /// Sets the millisecond delta reported to `WaitMillis` nodes on subsequent agent ticks.
+    pub fn set_delta_millis(&mut self, delta_millis: u32) {
+        self.settings.delta_millis = delta_millis;
+    }
+
+    pub fn delta_millis(&self) -> u32 {
+        self.settings.delta_millis
+    }
+
+    pub fn root(&self) -> &BehaviorNode<A, C> {
+        &self.root
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// Fresh state for a new agent, starting from `blackboard`.
+    pub fn new_agent<B: BlackboardStore>(&self, blackboard: B) -> AgentState<B> {
+        AgentState {
+            states: vec![NodeState::default(); self.node_count],
+            blackboard,
+            tick_count: 0,
+            scratch: TickScratch::new(),
+        }
+    }
+
+    pub fn tick_agent<B, AH, CH, O>(
+        &self,
+        agent: &mut AgentState<B>,
+        action_handler: &mut AH,
+        condition_handler: &CH,
+        observer: &mut O,
+    ) -> Result<TickOutcome, TreeError>
+    where
+        B: BlackboardStore,
+        AH: ActionHandler<A, B>,
+        CH: ConditionHandler<C, B>,
+        O: Observer,
+    {
+        self.tick_agent_with(agent, 1, None, action_handler, condition_handler, observer)
+    }
+
+    /// Ticks `agent` by `delta_ticks`; fails without ticking if the agent's state was built
+    /// for a tree with a different node count.
+    pub fn tick_agent_with<'a, B, AH, CH, O>(
+        &self,
+        agent: &'a mut AgentState<B>,
+        delta_ticks: u32,
+        rng: Option<&'a mut dyn RngCore>,
+        action_handler: &mut AH,
+        condition_handler: &CH,
+        observer: &mut O,
+    ) -> Result<TickOutcome, TreeError>
+    where
+        B: BlackboardStore,
+        AH: ActionHandler<A, B>,
+        CH: ConditionHandler<C, B>,
+        O: Observer,
+    {
+        self.tick_agent_with_user_data(
+            agent,
+            delta_ticks,
+            rng,
+            None,
+            action_handler,
+            condition_handler,
+            observer,
+        )
+    }
+
+    /// Like [`tick_agent_with`](Self::tick_agent_with), exposing `user_data` to handlers via
+    /// [`Context::user_data`](crate::Context::user_data).
+    #[allow(clippy::too_many_arguments)]
+    pub fn tick_agent_with_user_data<'a, B, AH, CH, O>(
+        &self,
+        agent: &'a mut AgentState<B>,
+        delta_ticks: u32,
+        rng: Option<&'a mut dyn RngCore>,
+        user_data: Option<&'a mut dyn Any>,
+        action_handler: &mut AH,
+        condition_handler: &CH,
+        observer: &mut O,
+    ) -> Result<TickOutcome, TreeError>
+    where
+        B: BlackboardStore,
+        AH: ActionHandler<A, B>,
+        CH: ConditionHandler<C, B>,
+        O: Observer,
+    {
+        if agent.states.len() != self.node_count {
+            return Err(TreeError::StateSizeMismatch {
+                expected: self.node_count,
+                actual: agent.states.len(),
+            });
+        }
+        agent.tick_count = agent.tick_count.saturating_add(delta_ticks as u64);
+        let run = RootTick {
+            states: &mut agent.states,
+            blackboard: &mut agent.blackboard,
+            scratch: &mut agent.scratch,
+            tick: agent.tick_count,
+            delta_ticks,
+            rng,
+            user_data,
+        };
+        Ok(tick_root::<F, _, _, _, _, _, _>(
+            &self.root,
+            &self.library,
+            &self.settings,
+            run,
+            action_handler,
+            condition_handler,
+            observer,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::SharedTree;
+    use crate::{
+        ActionHandler, BehaviorNode, Blackboard, ConditionHandler, Context, NoOpObserver, Status,
+        TreeConfig, TreeError,
+    };
+
+    struct RecordingActions {
+        calls: Vec<u32>,
+    }
+
+    impl ActionHandler<u32> for RecordingActions {
+        fn execute(&mut self, action: &u32, _ctx: &mut Context) -> Status {
+            self.calls.push(*action);
+            Status::Success
+        }
+    }
+
+    struct BlackboardConditions;
+
+    impl ConditionHandler<u32> for BlackboardConditions {
+        fn check(&self, condition: &u32, ctx: &Context) -> bool {
+            ctx.blackboard().get_bool(*condition).unwrap_or(false)
+        }
+    }
+
+    #[test]
+    fn shared_tree_ticks_agents_independently() {
+        let tree = SharedTree::new(BehaviorNode::Selector(vec![
+            BehaviorNode::Sequence(vec![BehaviorNode::Condition(1), BehaviorNode::Action(10)]),
+            BehaviorNode::Sequence(vec![BehaviorNode::Wait(2), BehaviorNode::Action(20)]),
+        ]));
+        let mut hungry = Blackboard::new();
+        hungry.set_bool(1, true);
+        let mut agents = [tree.new_agent(hungry), tree.new_agent(Blackboard::new())];
+        let mut actions = RecordingActions { calls: Vec::new() };
+        let mut observer = NoOpObserver;
+
+        let statuses: Vec<Status> = agents
+            .iter_mut()
+            .map(|agent| {
+                tree.tick_agent(agent, &mut actions, &BlackboardConditions, &mut observer)
+                    .unwrap()
+                    .status
+            })
+            .collect();
+        assert_eq!(statuses, vec![Status::Success, Status::Running]);
+        assert_eq!(actions.calls, vec![10]);
+
+        let outcome = tree
+            .tick_agent(
+                &mut agents[1],
+                &mut actions,
+                &BlackboardConditions,
+                &mut observer,
+            )
+            .unwrap();
+        assert_eq!(outcome.status, Status::Success);
+        assert_eq!(actions.calls, vec![10, 20]);
+        assert_eq!(agents[0].tick_count, 1);
+        assert_eq!(agents[1].tick_count, 2);
+    }
+
+    #[test]
+    fn shared_tree_honours_tick_settings() {
+        let mut tree = SharedTree::new(BehaviorNode::Sequence(vec![
+            BehaviorNode::WaitMillis(500),
+            BehaviorNode::Sequence(Vec::new()),
+            BehaviorNode::Action(1),
+        ]));
+        tree.set_delta_millis(300);
+        tree.set_config(TreeConfig {
+            empty_sequence_status: Status::Failure,
+            ..TreeConfig::default()
+        });
+        let mut agent = tree.new_agent(Blackboard::new());
+        let mut actions = RecordingActions { calls: Vec::new() };
+        let mut observer = NoOpObserver;
+
+        let mut tick = || {
+            tree.tick_agent(
+                &mut agent,
+                &mut actions,
+                &BlackboardConditions,
+                &mut observer,
+            )
+            .unwrap()
+            .status
+        };
+        assert_eq!(tick(), Status::Running);
+        assert_eq!(tick(), Status::Failure);
+        assert!(actions.calls.is_empty());
+    }
+
+    #[test]
+    fn shared_tree_rejects_mismatched_agent() {
+        let tree = SharedTree::new(BehaviorNode::Sequence(vec![
+            BehaviorNode::Action(1),
+            BehaviorNode::Action(2),
+        ]));
+        let other = SharedTree::new(BehaviorNode::<u32, u32>::Action(1));
+        let mut agent = other.new_agent(Blackboard::new());
+        let mut actions = RecordingActions { calls: Vec::new() };
+
+        let result = tree.tick_agent(
+            &mut agent,
+            &mut actions,
+            &BlackboardConditions,
+            &mut NoOpObserver,
+        );
+        assert_eq!(
+            result,
+            Err(TreeError::StateSizeMismatch {
+                expected: 3,
+                actual: 1,
+            })
+        );
+        assert_eq!(agent.tick_count, 0);
+        assert!(actions.calls.is_empty());
+    }
+}
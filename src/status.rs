@@ -28,9 +28,38 @@ impl Status {
     }
 }
 
+/// Result of ticking a whole tree, including whether an action aborted the tick.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TickOutcome {
+    pub status: Status,
+    pub aborted: bool,
+}
+
+impl TickOutcome {
+    pub fn completed(status: Status) -> Self {
+        Self {
+            status,
+            aborted: false,
+        }
+    }
+
+    pub fn aborted() -> Self {
+        Self {
+            status: Status::Failure,
+            aborted: true,
+        }
+    }
+}
+
+impl PartialEq<Status> for TickOutcome {
+    fn eq(&self, other: &Status) -> bool {
+        self.status == *other
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Status;
+    use super::{Status, TickOutcome};
 
     #[test]
     fn status_invert_success() {
@@ -67,4 +96,11 @@ mod tests {
         assert!(!Status::Running.is_failure());
         assert!(!Status::Success.is_failure());
     }
+
+    #[test]
+    fn tick_outcome_compares_with_status() {
+        assert_eq!(TickOutcome::completed(Status::Running), Status::Running);
+        assert_eq!(TickOutcome::aborted(), Status::Failure);
+        assert!(TickOutcome::aborted().aborted);
+    }
 }
@@ -0,0 +1,91 @@
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
+
+use crate::BehaviorNode;
+
+/// Deepest chain of nested `SubtreeRef` nodes ticked before the innermost ref fails, so
+/// self-referencing libraries cannot overflow the stack.
+pub const MAX_SUBTREE_DEPTH: u32 = 32;
+
+/// Shared subtrees that `BehaviorNode::SubtreeRef` nodes resolve by id at tick time.
+#[derive(Debug)]
+pub struct SubtreeLibrary<A, C> {
+    subtrees: BTreeMap<u32, Arc<BehaviorNode<A, C>>>,
+}
+
+impl<A, C> SubtreeLibrary<A, C> {
+    pub fn new() -> Self {
+        Self {
+            subtrees: BTreeMap::new(),
+        }
+    }
+
+    /// Registers `subtree` under `id`, replacing any previous entry.
+    pub fn insert(&mut self, id: u32, subtree: BehaviorNode<A, C>) {
+        self.insert_shared(id, Arc::new(subtree));
+    }
+
+    /// Registers an already shared subtree, e.g. one used by several libraries.
+    pub fn insert_shared(&mut self, id: u32, subtree: Arc<BehaviorNode<A, C>>) {
+        self.subtrees.insert(id, subtree);
+    }
+
+    pub fn with(mut self, id: u32, subtree: BehaviorNode<A, C>) -> Self {
+        self.insert(id, subtree);
+        self
+    }
+
+    pub fn get(&self, id: u32) -> Option<&BehaviorNode<A, C>> {
+        self.subtrees.get(&id).map(Arc::as_ref)
+    }
+
+    pub fn contains(&self, id: u32) -> bool {
+        self.subtrees.contains_key(&id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.subtrees.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.subtrees.is_empty()
+    }
+}
+
+impl<A, C> Clone for SubtreeLibrary<A, C> {
+    fn clone(&self) -> Self {
+        Self {
+            subtrees: self.subtrees.clone(),
+        }
+    }
+}
+
+impl<A, C> Default for SubtreeLibrary<A, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+    use alloc::vec;
+
+    use super::SubtreeLibrary;
+    use crate::BehaviorNode;
+
+    #[test]
+    fn subtree_library_shares_nodes() {
+        let shared = Arc::new(BehaviorNode::<u32, u32>::Action(1));
+        let mut library =
+            SubtreeLibrary::new().with(1, BehaviorNode::Sequence(vec![BehaviorNode::Action(2)]));
+        library.insert_shared(2, Arc::clone(&shared));
+
+        let copy = library.clone();
+        assert_eq!(copy.len(), 2);
+        assert!(copy.contains(1));
+        assert_eq!(copy.get(2), Some(&BehaviorNode::Action(1)));
+        assert_eq!(copy.get(3), None);
+        assert_eq!(Arc::strong_count(&shared), 3);
+    }
+}
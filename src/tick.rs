@@ -1,23 +1,162 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use core::any::Any;
+
+use rand_core::RngCore;
+
+use crate::float::Float;
+use crate::subtree::{SubtreeLibrary, MAX_SUBTREE_DEPTH};
 use crate::{
-    ActionHandler, BehaviorNode, ConditionHandler, Context, Decorator, Observer, ParallelPolicy,
-    Status,
+    ActionHandler, BehaviorNode, BlackboardStore, ConditionHandler, Context, Decorator,
+    NoOpObserver, Observer, ParallelPolicy, Status, TickOutcome, TreeConfig,
 };
 
+/// Most children a composite may have, since latched child indices are stored as `u16`.
+pub const MAX_CHILDREN: usize = u16::MAX as usize + 1;
+
 #[derive(Clone, Debug, Default)]
 pub struct NodeState {
-    pub running_child: usize,
+    /// Child to resume from in sequences and selectors, or step in shuffled composites; see
+    /// [`running_index`](Self::running_index).
+    pub running_child: u16,
+    /// Ticks counted by `Wait`, `Cooldown`, `Timeout`, `Delay`, `RunningTimeout` and
+    /// `TimedSelector`; milliseconds for `WaitMillis`.
     pub tick_counter: u32,
+    /// Completed passes of `Repeat`, `Retry` and `RepeatTolerant`.
     pub iteration_count: u32,
-    pub selected_child: Option<usize>,
-    pub random_selection: Option<usize>,
+    /// Times this node has been entered; survives `reset` for profiling.
+    pub enter_count: u32,
+    /// `Context::tick` of the most recent entry; survives `reset`.
+    pub last_enter_tick: u64,
+    /// Latched child of reactive, utility, reasoner and timed selectors, the grandchild a
+    /// `RunningTimeout` is timing, or `Some(0)` once a `WaitUntil` has released its child;
+    /// stored as `u16` to keep states small, see [`selected_index`](Self::selected_index).
+    pub selected_child: Option<u16>,
+    /// Latched pick of random and weighted selectors, or the roll of `Chance`; see
+    /// [`random_index`](Self::random_index).
+    pub random_selection: Option<u16>,
+    /// Status of the most recent exit; survives `reset` but is cleared when a Running node
+    /// is aborted.
+    pub last_status: Option<Status>,
+    extra: Option<Box<NodeExtra>>,
+}
+
+/// State only a few node kinds need, boxed on first use so [`NodeState`] stays small; the box
+/// is kept across `reset` so steady-state ticks don't allocate.
+#[derive(Clone, Debug, Default)]
+pub struct NodeExtra {
+    /// Failed passes of `RepeatTolerant`.
+    pub failure_count: u32,
+    pub shuffle_order: Vec<usize>,
+    /// Previous reasoner pick; survives `reset` so momentum can favour it.
+    pub last_selection: Option<usize>,
+    /// Duration rolled by a `WaitRandom` node for its current wait.
+    pub rolled_wait: Option<u32>,
+    /// Tick a `Throttle` node last succeeded on; survives `reset` so the rate limit holds.
+    pub last_success_tick: Option<u64>,
+    /// Child result a `Yield` decorator is holding until its next tick.
+    pub yielded: Option<Status>,
+    /// Child completions counted by `Limit`, or nonzero once a `Once` child has succeeded;
+    /// survives `reset`, so only a full tree reset clears it.
+    pub completions: u32,
+    /// States of the subtree a `SubtreeRef` resolved to, indexed by subtree-local ids.
+    pub subtree_states: Vec<NodeState>,
 }
 
 impl NodeState {
+    pub fn running_index(&self) -> usize {
+        usize::from(self.running_child)
+    }
+
+    /// Records where to resume; indices past `u16::MAX` saturate, so an oversized composite
+    /// re-runs some children rather than skipping any.
+    pub fn set_running_index(&mut self, index: usize) {
+        self.running_child = u16::try_from(index).unwrap_or(u16::MAX);
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.selected_child.map(usize::from)
+    }
+
+    /// Latches `index`; indices past `u16::MAX` aren't latched and are re-selected next tick, so
+    /// [`validate_tree`](crate::validate_tree) rejects composites wider than [`MAX_CHILDREN`].
+    pub fn set_selected_index(&mut self, index: Option<usize>) {
+        self.selected_child = index.and_then(|index| u16::try_from(index).ok());
+    }
+
+    pub fn random_index(&self) -> Option<usize> {
+        self.random_selection.map(usize::from)
+    }
+
+    /// Latches `index` like [`set_selected_index`](Self::set_selected_index).
+    pub fn set_random_index(&mut self, index: Option<usize>) {
+        self.random_selection = index.and_then(|index| u16::try_from(index).ok());
+    }
+
+    /// Per-variant state, or `None` if this node never needed any.
+    pub fn extra(&self) -> Option<&NodeExtra> {
+        self.extra.as_deref()
+    }
+
+    /// Per-variant state, allocated on first use.
+    pub fn extra_mut(&mut self) -> &mut NodeExtra {
+        self.extra.get_or_insert_with(Box::default)
+    }
+
+    /// Clears execution state while keeping the profiling counters; nested subtree states are
+    /// left to `SubtreeRef` handling.
     pub fn reset(&mut self) {
-        *self = Self::default();
+        let mut extra = self.extra.take();
+        if let Some(extra) = extra.as_deref_mut() {
+            extra.reset();
+        }
+        *self = Self {
+            enter_count: self.enter_count,
+            last_enter_tick: self.last_enter_tick,
+            last_status: self.last_status,
+            extra,
+            ..Self::default()
+        };
+    }
+}
+
+impl NodeExtra {
+    /// Clears the per-pass fields, keeping buffers, momentum, throttle, limit, once and subtree
+    /// state.
+    fn reset(&mut self) {
+        self.failure_count = 0;
+        self.shuffle_order.clear();
+        self.rolled_wait = None;
+        self.yielded = None;
+    }
+}
+
+/// Reusable buffers borrowed by scoring nodes so steady-state ticks don't allocate.
+#[derive(Clone, Debug, Default)]
+pub struct TickScratch {
+    indices: Vec<usize>,
+    scores: Vec<(usize, f32)>,
+}
+
+impl TickScratch {
+    pub fn new() -> Self {
+        Self::default()
     }
 }
 
+/// Lends the context's scratch to `f`, falling back to empty buffers when none is attached.
+fn with_scratch<'a, B, R>(
+    ctx: &mut Context<'a, B>,
+    f: impl FnOnce(&mut Context<'a, B>, &mut TickScratch) -> R,
+) -> R {
+    let mut taken = ctx.take_scratch();
+    let mut fallback = TickScratch::default();
+    let result = f(ctx, taken.as_deref_mut().unwrap_or(&mut fallback));
+    ctx.restore_scratch(taken);
+    result
+}
+
 /// Returns the number of nodes in pre-order traversal.
 pub fn assign_ids<A, C>(node: &BehaviorNode<A, C>) -> usize {
     subtree_size(node)
@@ -27,19 +166,136 @@ pub(crate) fn subtree_size<A, C>(node: &BehaviorNode<A, C>) -> usize {
     match node {
         BehaviorNode::Sequence(children)
         | BehaviorNode::Selector(children)
-        | BehaviorNode::RandomSelector(children) => {
+        | BehaviorNode::ReactiveSelector(children)
+        | BehaviorNode::ShuffledSelector { children, .. }
+        | BehaviorNode::ShuffledSequence { children, .. }
+        | BehaviorNode::RandomSelector { children, .. } => {
             1 + children.iter().map(subtree_size).sum::<usize>()
         }
         BehaviorNode::Parallel { children, .. }
+        | BehaviorNode::InterruptParallel { children, .. }
         | BehaviorNode::UtilitySelector { children, .. }
-        | BehaviorNode::WeightedSelector { children, .. } => {
+        | BehaviorNode::WeightedSelector { children, .. }
+        | BehaviorNode::BlackboardWeightedSelector { children, .. }
+        | BehaviorNode::Reasoner { children, .. } => {
             1 + children.iter().map(subtree_size).sum::<usize>()
         }
         BehaviorNode::Decorator { child, .. } => 1 + subtree_size(child),
-        BehaviorNode::Action(_) | BehaviorNode::Condition(_) | BehaviorNode::Wait(_) => 1,
+        BehaviorNode::TimedSelector {
+            primary, fallback, ..
+        } => 1 + subtree_size(primary) + subtree_size(fallback),
+        BehaviorNode::Action(_)
+        | BehaviorNode::Condition(_)
+        | BehaviorNode::BlackboardCondition(_)
+        | BehaviorNode::Compare { .. }
+        | BehaviorNode::SetBlackboard { .. }
+        | BehaviorNode::ClearBlackboard(_)
+        | BehaviorNode::Wait(_)
+        | BehaviorNode::WaitMillis(_)
+        | BehaviorNode::WaitRandom { .. }
+        | BehaviorNode::Throttle(_)
+        | BehaviorNode::SubtreeRef(_) => 1,
+    }
+}
+
+/// Fills `order` with a Fisher-Yates permutation of `0..len` drawn from the context RNG.
+fn shuffle_into<B>(order: &mut Vec<usize>, len: usize, seed: Option<u32>, ctx: &mut Context<B>) {
+    order.clear();
+    order.extend(0..len);
+    for i in (1..len).rev() {
+        let j = (seeded_roll(ctx, seed) as usize) % (i + 1);
+        order.swap(i, j);
+    }
+}
+
+/// Draws from the context RNG, hashing in `seed` so seeded nodes get independent streams.
+fn seeded_roll<B>(ctx: &mut Context<B>, seed: Option<u32>) -> u32 {
+    let roll = ctx.rng().next_u32();
+    match seed {
+        Some(seed) => {
+            let mut x = roll ^ seed.wrapping_mul(0x9E37_79B9);
+            x = (x ^ (x >> 16)).wrapping_mul(0x85EB_CA6B);
+            x = (x ^ (x >> 13)).wrapping_mul(0xC2B2_AE35);
+            x ^ (x >> 16)
+        }
+        None => roll,
+    }
+}
+
+/// Direct children of `node` in tick order; empty for leaves.
+pub(crate) fn node_children<A, C>(node: &BehaviorNode<A, C>) -> NodeChildren<'_, A, C> {
+    match node {
+        BehaviorNode::Sequence(children)
+        | BehaviorNode::Selector(children)
+        | BehaviorNode::ReactiveSelector(children)
+        | BehaviorNode::ShuffledSelector { children, .. }
+        | BehaviorNode::ShuffledSequence { children, .. }
+        | BehaviorNode::RandomSelector { children, .. } => NodeChildren::new(None, children),
+        BehaviorNode::Parallel { children, .. }
+        | BehaviorNode::InterruptParallel { children, .. }
+        | BehaviorNode::UtilitySelector { children, .. }
+        | BehaviorNode::WeightedSelector { children, .. }
+        | BehaviorNode::BlackboardWeightedSelector { children, .. }
+        | BehaviorNode::Reasoner { children, .. } => NodeChildren::new(None, children),
+        BehaviorNode::Decorator { child, .. } => {
+            NodeChildren::new(None, core::slice::from_ref(child.as_ref()))
+        }
+        BehaviorNode::TimedSelector {
+            primary, fallback, ..
+        } => NodeChildren::new(Some(primary), core::slice::from_ref(fallback.as_ref())),
+        BehaviorNode::Action(_)
+        | BehaviorNode::Condition(_)
+        | BehaviorNode::BlackboardCondition(_)
+        | BehaviorNode::Compare { .. }
+        | BehaviorNode::SetBlackboard { .. }
+        | BehaviorNode::ClearBlackboard(_)
+        | BehaviorNode::Wait(_)
+        | BehaviorNode::WaitMillis(_)
+        | BehaviorNode::WaitRandom { .. }
+        | BehaviorNode::Throttle(_)
+        | BehaviorNode::SubtreeRef(_) => NodeChildren::new(None, &[]),
+    }
+}
+
+/// Iterator returned by [`node_children`]; `head` covers children stored outside a slice.
+pub(crate) struct NodeChildren<'a, A, C> {
+    head: Option<&'a BehaviorNode<A, C>>,
+    rest: core::slice::Iter<'a, BehaviorNode<A, C>>,
+}
+
+impl<'a, A, C> NodeChildren<'a, A, C> {
+    fn new(head: Option<&'a BehaviorNode<A, C>>, rest: &'a [BehaviorNode<A, C>]) -> Self {
+        Self {
+            head,
+            rest: rest.iter(),
+        }
+    }
+}
+
+impl<A, C> Clone for NodeChildren<'_, A, C> {
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head,
+            rest: self.rest.clone(),
+        }
+    }
+}
+
+impl<'a, A, C> Iterator for NodeChildren<'a, A, C> {
+    type Item = &'a BehaviorNode<A, C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.head.take().or_else(|| self.rest.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(self.head.is_some()) + self.rest.len();
+        (len, Some(len))
     }
 }
 
+impl<A, C> ExactSizeIterator for NodeChildren<'_, A, C> {}
+
 fn child_id_for_index<A, C>(
     children: &[BehaviorNode<A, C>],
     parent_id: usize,
@@ -52,62 +308,380 @@ fn child_id_for_index<A, C>(
     child_id
 }
 
-fn reset_subtree<A, C>(node: &BehaviorNode<A, C>, node_id: usize, states: &mut [NodeState]) {
+/// Child results gathered by a `Parallel` node during one tick.
+#[derive(Default)]
+struct ParallelTally {
+    success_count: usize,
+    failure_count: usize,
+    success_weight: u32,
+    failure_weight: u32,
+}
+
+impl ParallelTally {
+    fn record(&mut self, status: Status, weight: u32) {
+        match status {
+            Status::Success => {
+                self.success_count += 1;
+                self.success_weight = self.success_weight.saturating_add(weight);
+            }
+            Status::Failure => {
+                self.failure_count += 1;
+                self.failure_weight = self.failure_weight.saturating_add(weight);
+            }
+            Status::Running => {}
+        }
+    }
+
+    /// The parallel's status under `policy`; Running while the outcome is still open.
+    fn outcome(&self, policy: &ParallelPolicy, child_count: usize) -> Status {
+        match policy {
+            ParallelPolicy::RequireAll => {
+                if self.failure_count > 0 {
+                    Status::Failure
+                } else if self.success_count == child_count {
+                    Status::Success
+                } else {
+                    Status::Running
+                }
+            }
+            ParallelPolicy::RequireOne => {
+                if self.success_count > 0 {
+                    Status::Success
+                } else if self.failure_count == child_count {
+                    Status::Failure
+                } else {
+                    Status::Running
+                }
+            }
+            ParallelPolicy::RequireN(n) => {
+                if self.success_count >= *n {
+                    Status::Success
+                } else if child_count.saturating_sub(self.failure_count) < *n {
+                    Status::Failure
+                } else {
+                    Status::Running
+                }
+            }
+            ParallelPolicy::RequireWeight { threshold, weights } => {
+                let total = weights
+                    .iter()
+                    .take(child_count)
+                    .fold(0u32, |sum, w| sum.saturating_add(*w));
+                if self.success_weight >= *threshold {
+                    Status::Success
+                } else if total.saturating_sub(self.failure_weight) < *threshold {
+                    Status::Failure
+                } else {
+                    Status::Running
+                }
+            }
+        }
+    }
+}
+
+/// Resets `node` and its descendants, reporting each node that was Running as cancelled and
+/// notifying the handler of any action that was Running.
+fn reset_subtree<A, C, B, AH, O>(
+    node: &BehaviorNode<A, C>,
+    node_id: usize,
+    states: &mut [NodeState],
+    ctx: &mut Context<B>,
+    library: Option<&SubtreeLibrary<A, C>>,
+    action_handler: &mut AH,
+    observer: &mut O,
+) where
+    AH: ActionHandler<A, B>,
+    O: Observer,
+{
+    let was_running = states[node_id].last_status == Some(Status::Running);
+    if was_running {
+        states[node_id].last_status = None;
+        observer.on_cancel(node_id);
+    }
     states[node_id].reset();
     match node {
         BehaviorNode::Sequence(children)
         | BehaviorNode::Selector(children)
-        | BehaviorNode::RandomSelector(children) => {
+        | BehaviorNode::ReactiveSelector(children)
+        | BehaviorNode::ShuffledSelector { children, .. }
+        | BehaviorNode::ShuffledSequence { children, .. }
+        | BehaviorNode::RandomSelector { children, .. } => {
             let mut child_id = node_id + 1;
             for child in children {
-                reset_subtree(child, child_id, states);
+                reset_subtree(
+                    child,
+                    child_id,
+                    states,
+                    ctx,
+                    library,
+                    action_handler,
+                    observer,
+                );
                 child_id += subtree_size(child);
             }
         }
         BehaviorNode::Parallel { children, .. }
+        | BehaviorNode::InterruptParallel { children, .. }
         | BehaviorNode::UtilitySelector { children, .. }
-        | BehaviorNode::WeightedSelector { children, .. } => {
+        | BehaviorNode::WeightedSelector { children, .. }
+        | BehaviorNode::BlackboardWeightedSelector { children, .. }
+        | BehaviorNode::Reasoner { children, .. } => {
             let mut child_id = node_id + 1;
             for child in children {
-                reset_subtree(child, child_id, states);
+                reset_subtree(
+                    child,
+                    child_id,
+                    states,
+                    ctx,
+                    library,
+                    action_handler,
+                    observer,
+                );
                 child_id += subtree_size(child);
             }
         }
         BehaviorNode::Decorator { child, .. } => {
-            reset_subtree(child, node_id + 1, states);
+            reset_subtree(
+                child,
+                node_id + 1,
+                states,
+                ctx,
+                library,
+                action_handler,
+                observer,
+            );
+        }
+        BehaviorNode::TimedSelector {
+            primary, fallback, ..
+        } => {
+            let fallback_id = node_id + 1 + subtree_size(primary);
+            reset_subtree(
+                primary,
+                node_id + 1,
+                states,
+                ctx,
+                library,
+                action_handler,
+                observer,
+            );
+            reset_subtree(
+                fallback,
+                fallback_id,
+                states,
+                ctx,
+                library,
+                action_handler,
+                observer,
+            );
+        }
+        BehaviorNode::Action(action) => {
+            if was_running {
+                action_handler.on_abort(action, ctx);
+            }
+        }
+        BehaviorNode::Condition(_)
+        | BehaviorNode::BlackboardCondition(_)
+        | BehaviorNode::Compare { .. }
+        | BehaviorNode::SetBlackboard { .. }
+        | BehaviorNode::ClearBlackboard(_)
+        | BehaviorNode::Wait(_)
+        | BehaviorNode::WaitMillis(_)
+        | BehaviorNode::WaitRandom { .. }
+        | BehaviorNode::Throttle(_) => {}
+        BehaviorNode::SubtreeRef(id) => {
+            let subtree = library.and_then(|library| library.get(*id));
+            let nested = states[node_id]
+                .extra
+                .as_deref_mut()
+                .map(|e| &mut e.subtree_states);
+            if let Some((subtree, nested)) = subtree.zip(nested.filter(|n| !n.is_empty())) {
+                let mut nested = core::mem::take(nested);
+                reset_subtree(
+                    subtree,
+                    0,
+                    &mut nested,
+                    ctx,
+                    library,
+                    action_handler,
+                    &mut NoOpObserver,
+                );
+                states[node_id].extra_mut().subtree_states = nested;
+            }
         }
-        BehaviorNode::Action(_) | BehaviorNode::Condition(_) | BehaviorNode::Wait(_) => {}
     }
 }
 
-pub fn tick_node<A, C, AH, CH, O>(
+/// Settings every root tick is built from, shared by [`BehaviorTree`](crate::BehaviorTree) and
+/// [`SharedTree`](crate::SharedTree).
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TickSettings {
+    pub(crate) leaf_budget: Option<u32>,
+    pub(crate) delta_millis: u32,
+    pub(crate) config: TreeConfig,
+}
+
+/// Runtime state and per-tick inputs borrowed by [`tick_root`].
+pub(crate) struct RootTick<'a, B> {
+    pub(crate) states: &'a mut [NodeState],
+    pub(crate) blackboard: &'a mut B,
+    pub(crate) scratch: &'a mut TickScratch,
+    pub(crate) tick: u64,
+    pub(crate) delta_ticks: u32,
+    pub(crate) rng: Option<&'a mut dyn RngCore>,
+    pub(crate) user_data: Option<&'a mut dyn Any>,
+}
+
+/// Ticks `root` with a context built from `settings`, unwinding the whole tree through
+/// [`reset_subtree`] if a handler requested an abort.
+pub(crate) fn tick_root<F, A, C, B, AH, CH, O>(
+    root: &BehaviorNode<A, C>,
+    library: &SubtreeLibrary<A, C>,
+    settings: &TickSettings,
+    run: RootTick<'_, B>,
+    action_handler: &mut AH,
+    condition_handler: &CH,
+    observer: &mut O,
+) -> TickOutcome
+where
+    F: Float,
+    B: BlackboardStore,
+    AH: ActionHandler<A, B>,
+    CH: ConditionHandler<C, B>,
+    O: Observer,
+{
+    let mut ctx = Context::new(run.tick, run.delta_ticks, run.blackboard, run.rng)
+        .with_leaf_budget(settings.leaf_budget)
+        .with_delta_millis(settings.delta_millis)
+        .with_empty_composite_statuses(
+            settings.config.empty_sequence_status,
+            settings.config.empty_selector_status,
+        )
+        .with_user_data(run.user_data)
+        .with_scratch(Some(run.scratch));
+    let status = tick_node_with_library::<F, _, _, _, _, _, _>(
+        root,
+        0,
+        run.states,
+        &mut ctx,
+        Some(library),
+        action_handler,
+        condition_handler,
+        observer,
+    );
+    if ctx.abort_requested() {
+        reset_subtree(
+            root,
+            0,
+            run.states,
+            &mut ctx,
+            Some(library),
+            action_handler,
+            observer,
+        );
+        TickOutcome::aborted()
+    } else {
+        TickOutcome::completed(status)
+    }
+}
+
+/// Ticks `node`, scoring utility selectors in `f32`; use [`tick_node_with_library`] for another
+/// float type.
+pub fn tick_node<A, C, B, AH, CH, O>(
+    node: &BehaviorNode<A, C>,
+    node_id: usize,
+    states: &mut [NodeState],
+    ctx: &mut Context<B>,
+    action_handler: &mut AH,
+    condition_handler: &CH,
+    observer: &mut O,
+) -> Status
+where
+    B: BlackboardStore,
+    AH: ActionHandler<A, B>,
+    CH: ConditionHandler<C, B>,
+    O: Observer,
+{
+    tick_node_with_library::<f32, _, _, _, _, _, _>(
+        node,
+        node_id,
+        states,
+        ctx,
+        None,
+        action_handler,
+        condition_handler,
+        observer,
+    )
+}
+
+/// Like [`tick_node`], resolving `SubtreeRef` nodes against `library`; unresolved refs fail.
+///
+/// A referenced subtree keeps its own state in the ref node's `NodeExtra::subtree_states`, so
+/// its nodes have ids local to the subtree and aren't reported to `observer`.
+#[allow(clippy::too_many_arguments)]
+pub fn tick_node_with_library<F, A, C, B, AH, CH, O>(
     node: &BehaviorNode<A, C>,
     node_id: usize,
     states: &mut [NodeState],
-    ctx: &mut Context,
+    ctx: &mut Context<B>,
+    library: Option<&SubtreeLibrary<A, C>>,
     action_handler: &mut AH,
     condition_handler: &CH,
     observer: &mut O,
 ) -> Status
 where
-    AH: ActionHandler<A>,
-    CH: ConditionHandler<C>,
+    F: Float,
+    B: BlackboardStore,
+    AH: ActionHandler<A, B>,
+    CH: ConditionHandler<C, B>,
     O: Observer,
 {
+    if ctx.abort_requested() {
+        return Status::Failure;
+    }
+    let budgeted = matches!(
+        node,
+        BehaviorNode::Action(_)
+            | BehaviorNode::Condition(_)
+            | BehaviorNode::BlackboardCondition(_)
+            | BehaviorNode::Compare { .. }
+            | BehaviorNode::SetBlackboard { .. }
+            | BehaviorNode::ClearBlackboard(_)
+    );
+    if budgeted && !ctx.consume_leaf() {
+        // Deferred to a later tick: the leaf never runs, so its state and events stay untouched.
+        return Status::Running;
+    }
+
     observer.on_enter(node_id);
+    states[node_id].enter_count = states[node_id].enter_count.saturating_add(1);
+    states[node_id].last_enter_tick = ctx.tick();
 
     let status = match node {
+        BehaviorNode::Sequence(children) | BehaviorNode::ShuffledSequence { children, .. }
+            if children.is_empty() =>
+        {
+            ctx.empty_sequence_status()
+        }
+        BehaviorNode::Selector(children)
+        | BehaviorNode::ReactiveSelector(children)
+        | BehaviorNode::ShuffledSelector { children, .. }
+        | BehaviorNode::RandomSelector { children, .. }
+            if children.is_empty() =>
+        {
+            ctx.empty_selector_status()
+        }
         BehaviorNode::Sequence(children) => {
-            let start = states[node_id].running_child.min(children.len());
+            let start = states[node_id].running_index().min(children.len());
             let mut child_id = child_id_for_index(children, node_id, start);
             let mut result = Status::Success;
 
             for (i, child) in children.iter().enumerate().skip(start) {
-                let child_status = tick_node(
+                let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
                     child,
                     child_id,
                     states,
                     ctx,
+                    library,
                     action_handler,
                     condition_handler,
                     observer,
@@ -115,7 +689,7 @@ where
 
                 match child_status {
                     Status::Running => {
-                        states[node_id].running_child = i;
+                        states[node_id].set_running_index(i);
                         result = Status::Running;
                         break;
                     }
@@ -136,16 +710,17 @@ where
             result
         }
         BehaviorNode::Selector(children) => {
-            let start = states[node_id].running_child.min(children.len());
+            let start = states[node_id].running_index().min(children.len());
             let mut child_id = child_id_for_index(children, node_id, start);
             let mut result = Status::Failure;
 
             for (i, child) in children.iter().enumerate().skip(start) {
-                let child_status = tick_node(
+                let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
                     child,
                     child_id,
                     states,
                     ctx,
+                    library,
                     action_handler,
                     condition_handler,
                     observer,
@@ -153,7 +728,7 @@ where
 
                 match child_status {
                     Status::Running => {
-                        states[node_id].running_child = i;
+                        states[node_id].set_running_index(i);
                         result = Status::Running;
                         break;
                     }
@@ -173,101 +748,295 @@ where
 
             result
         }
-        BehaviorNode::Parallel { policy, children } => {
-            let mut success_count = 0usize;
-            let mut failure_count = 0usize;
+        BehaviorNode::ReactiveSelector(children) => {
+            let previous = states[node_id].selected_index();
             let mut child_id = node_id + 1;
+            let mut result = Status::Failure;
+            let mut settled = None;
 
-            for child in children {
-                match tick_node(
+            for (i, child) in children.iter().enumerate() {
+                let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
                     child,
                     child_id,
                     states,
                     ctx,
+                    library,
                     action_handler,
                     condition_handler,
                     observer,
-                ) {
-                    Status::Success => success_count += 1,
-                    Status::Failure => failure_count += 1,
-                    Status::Running => {}
+                );
+                if child_status != Status::Failure {
+                    result = child_status;
+                    settled = Some(i);
+                    break;
                 }
                 child_id += subtree_size(child);
             }
 
-            match policy {
-                ParallelPolicy::RequireAll => {
-                    if failure_count > 0 {
-                        Status::Failure
-                    } else if success_count == children.len() {
-                        Status::Success
-                    } else {
-                        Status::Running
-                    }
+            if let Some(prev) = previous.filter(|prev| Some(*prev) != settled) {
+                if let Some(abandoned) = children.get(prev) {
+                    let abandoned_id = child_id_for_index(children, node_id, prev);
+                    reset_subtree(
+                        abandoned,
+                        abandoned_id,
+                        states,
+                        ctx,
+                        library,
+                        action_handler,
+                        observer,
+                    );
                 }
-                ParallelPolicy::RequireOne => {
-                    if success_count > 0 {
-                        Status::Success
-                    } else if failure_count == children.len() {
-                        Status::Failure
-                    } else {
-                        Status::Running
-                    }
+            }
+
+            if result == Status::Running {
+                states[node_id].set_selected_index(settled);
+            } else {
+                states[node_id].reset();
+            }
+
+            result
+        }
+        BehaviorNode::ShuffledSelector { children, seed }
+        | BehaviorNode::ShuffledSequence { children, seed } => {
+            // A selector stops on the first success, a sequence on the first failure.
+            let settle_on = if matches!(node, BehaviorNode::ShuffledSequence { .. }) {
+                Status::Failure
+            } else {
+                Status::Success
+            };
+            if states[node_id].extra().map_or(0, |e| e.shuffle_order.len()) != children.len() {
+                let order = &mut states[node_id].extra_mut().shuffle_order;
+                shuffle_into(order, children.len(), *seed, ctx);
+                states[node_id].running_child = 0;
+            }
+            let start = states[node_id].running_index().min(children.len());
+            let mut result = settle_on.invert();
+
+            for step in start..children.len() {
+                let idx = states[node_id].extra_mut().shuffle_order[step];
+                let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                    &children[idx],
+                    child_id_for_index(children, node_id, idx),
+                    states,
+                    ctx,
+                    library,
+                    action_handler,
+                    condition_handler,
+                    observer,
+                );
+
+                if child_status == Status::Running {
+                    states[node_id].set_running_index(step);
+                    result = Status::Running;
+                    break;
                 }
-                ParallelPolicy::RequireN(n) => {
-                    if success_count >= *n {
-                        Status::Success
-                    } else if children.len().saturating_sub(failure_count) < *n {
-                        Status::Failure
-                    } else {
-                        Status::Running
-                    }
+                if child_status == settle_on {
+                    result = settle_on;
+                    break;
                 }
             }
+
+            if result != Status::Running {
+                states[node_id].reset();
+            }
+
+            result
         }
-        BehaviorNode::Decorator { decorator, child } => {
-            let child_id = node_id + 1;
-            match decorator {
-                Decorator::Inverter => tick_node(
+        BehaviorNode::Parallel {
+            policy,
+            children,
+            short_circuit,
+        } => {
+            let mut tally = ParallelTally::default();
+            let mut outcome = Status::Running;
+            let mut child_id = node_id + 1;
+            let mut ticked = 0;
+
+            for (index, child) in children.iter().enumerate() {
+                let weight = match policy {
+                    ParallelPolicy::RequireWeight { weights, .. } => {
+                        weights.get(index).copied().unwrap_or(0)
+                    }
+                    _ => 0,
+                };
+                let status = tick_node_with_library::<F, _, _, _, _, _, _>(
                     child,
                     child_id,
                     states,
                     ctx,
+                    library,
                     action_handler,
                     condition_handler,
                     observer,
-                )
-                .invert(),
-                Decorator::Repeat(n) => {
-                    if *n == 0 {
-                        states[node_id].reset();
-                        reset_subtree(child, child_id, states);
-                        Status::Success
-                    } else {
-                        let child_status = tick_node(
-                            child,
-                            child_id,
-                            states,
-                            ctx,
-                            action_handler,
-                            condition_handler,
-                            observer,
-                        );
-                        match child_status {
-                            Status::Failure => {
-                                states[node_id].reset();
-                                reset_subtree(child, child_id, states);
-                                Status::Failure
-                            }
-                            Status::Success => {
-                                let next = states[node_id].iteration_count.saturating_add(1);
-                                states[node_id].iteration_count = next;
-                                if next >= *n {
-                                    states[node_id].reset();
-                                    reset_subtree(child, child_id, states);
-                                    Status::Success
-                                } else {
-                                    reset_subtree(child, child_id, states);
+                );
+                tally.record(status, weight);
+                child_id += subtree_size(child);
+                ticked = index + 1;
+
+                if *short_circuit {
+                    outcome = tally.outcome(policy, children.len());
+                    if outcome != Status::Running {
+                        break;
+                    }
+                }
+            }
+
+            if *short_circuit {
+                for child in &children[ticked..] {
+                    reset_subtree(
+                        child,
+                        child_id,
+                        states,
+                        ctx,
+                        library,
+                        action_handler,
+                        observer,
+                    );
+                    child_id += subtree_size(child);
+                }
+                outcome
+            } else {
+                tally.outcome(policy, children.len())
+            }
+        }
+        BehaviorNode::InterruptParallel {
+            policy,
+            children,
+            interrupts,
+        } => {
+            let mut tally = ParallelTally::default();
+            let mut child_id = node_id + 1;
+            let mut counted = 0;
+            let mut interrupted = false;
+
+            for (index, child) in children.iter().enumerate() {
+                let status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                    child,
+                    child_id,
+                    states,
+                    ctx,
+                    library,
+                    action_handler,
+                    condition_handler,
+                    observer,
+                );
+                child_id += subtree_size(child);
+                if interrupts.get(index).copied().unwrap_or(false) {
+                    if status == Status::Success {
+                        interrupted = true;
+                        break;
+                    }
+                } else {
+                    let weight = match policy {
+                        ParallelPolicy::RequireWeight { weights, .. } => {
+                            weights.get(counted).copied().unwrap_or(0)
+                        }
+                        _ => 0,
+                    };
+                    tally.record(status, weight);
+                    counted += 1;
+                }
+            }
+
+            if interrupted {
+                let mut child_id = node_id + 1;
+                for (index, child) in children.iter().enumerate() {
+                    if !interrupts.get(index).copied().unwrap_or(false) {
+                        reset_subtree(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            observer,
+                        );
+                    }
+                    child_id += subtree_size(child);
+                }
+                Status::Running
+            } else {
+                tally.outcome(policy, counted)
+            }
+        }
+        BehaviorNode::Decorator { decorator, child } => {
+            let child_id = node_id + 1;
+            match decorator {
+                Decorator::Inverter => tick_node_with_library::<F, _, _, _, _, _, _>(
+                    child,
+                    child_id,
+                    states,
+                    ctx,
+                    library,
+                    action_handler,
+                    condition_handler,
+                    observer,
+                )
+                .invert(),
+                Decorator::Repeat(n) => {
+                    if *n == 0 {
+                        states[node_id].reset();
+                        reset_subtree(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            observer,
+                        );
+                        Status::Success
+                    } else {
+                        let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            condition_handler,
+                            observer,
+                        );
+                        match child_status {
+                            Status::Failure => {
+                                states[node_id].reset();
+                                reset_subtree(
+                                    child,
+                                    child_id,
+                                    states,
+                                    ctx,
+                                    library,
+                                    action_handler,
+                                    observer,
+                                );
+                                Status::Failure
+                            }
+                            Status::Success => {
+                                let next = states[node_id].iteration_count.saturating_add(1);
+                                states[node_id].iteration_count = next;
+                                observer.on_iteration(node_id, next);
+                                if next >= *n {
+                                    states[node_id].reset();
+                                    reset_subtree(
+                                        child,
+                                        child_id,
+                                        states,
+                                        ctx,
+                                        library,
+                                        action_handler,
+                                        observer,
+                                    );
+                                    Status::Success
+                                } else {
+                                    reset_subtree(
+                                        child,
+                                        child_id,
+                                        states,
+                                        ctx,
+                                        library,
+                                        action_handler,
+                                        observer,
+                                    );
                                     Status::Running
                                 }
                             }
@@ -278,14 +1047,23 @@ where
                 Decorator::Retry(n) => {
                     if *n == 0 {
                         states[node_id].reset();
-                        reset_subtree(child, child_id, states);
+                        reset_subtree(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            observer,
+                        );
                         Status::Failure
                     } else {
-                        let child_status = tick_node(
+                        let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
                             child,
                             child_id,
                             states,
                             ctx,
+                            library,
                             action_handler,
                             condition_handler,
                             observer,
@@ -293,18 +1071,43 @@ where
                         match child_status {
                             Status::Success => {
                                 states[node_id].reset();
-                                reset_subtree(child, child_id, states);
+                                reset_subtree(
+                                    child,
+                                    child_id,
+                                    states,
+                                    ctx,
+                                    library,
+                                    action_handler,
+                                    observer,
+                                );
                                 Status::Success
                             }
                             Status::Failure => {
                                 let attempts = states[node_id].iteration_count.saturating_add(1);
                                 states[node_id].iteration_count = attempts;
+                                observer.on_iteration(node_id, attempts);
                                 if attempts >= *n {
                                     states[node_id].reset();
-                                    reset_subtree(child, child_id, states);
+                                    reset_subtree(
+                                        child,
+                                        child_id,
+                                        states,
+                                        ctx,
+                                        library,
+                                        action_handler,
+                                        observer,
+                                    );
                                     Status::Failure
                                 } else {
-                                    reset_subtree(child, child_id, states);
+                                    reset_subtree(
+                                        child,
+                                        child_id,
+                                        states,
+                                        ctx,
+                                        library,
+                                        action_handler,
+                                        observer,
+                                    );
                                     Status::Running
                                 }
                             }
@@ -312,6 +1115,87 @@ where
                         }
                     }
                 }
+                Decorator::RepeatTolerant {
+                    iterations,
+                    max_failures,
+                } => {
+                    if *iterations == 0 {
+                        states[node_id].reset();
+                        reset_subtree(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            observer,
+                        );
+                        Status::Success
+                    } else {
+                        let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            condition_handler,
+                            observer,
+                        );
+                        if child_status == Status::Running {
+                            Status::Running
+                        } else {
+                            let state = &mut states[node_id];
+                            state.iteration_count = state.iteration_count.saturating_add(1);
+                            observer.on_iteration(node_id, state.iteration_count);
+                            let failed = child_status == Status::Failure;
+                            let failures = state.extra().map_or(0, |e| e.failure_count);
+                            let failures = if failed {
+                                let failures = failures.saturating_add(1);
+                                state.extra_mut().failure_count = failures;
+                                failures
+                            } else {
+                                failures
+                            };
+                            if failed && failures >= *max_failures {
+                                states[node_id].reset();
+                                reset_subtree(
+                                    child,
+                                    child_id,
+                                    states,
+                                    ctx,
+                                    library,
+                                    action_handler,
+                                    observer,
+                                );
+                                Status::Failure
+                            } else if state.iteration_count >= *iterations {
+                                states[node_id].reset();
+                                reset_subtree(
+                                    child,
+                                    child_id,
+                                    states,
+                                    ctx,
+                                    library,
+                                    action_handler,
+                                    observer,
+                                );
+                                Status::Success
+                            } else {
+                                reset_subtree(
+                                    child,
+                                    child_id,
+                                    states,
+                                    ctx,
+                                    library,
+                                    action_handler,
+                                    observer,
+                                );
+                                Status::Running
+                            }
+                        }
+                    }
+                }
                 Decorator::Cooldown(cooldown_ticks) => {
                     let remaining = states[node_id].tick_counter;
                     if remaining > 0 {
@@ -319,11 +1203,12 @@ where
                         states[node_id].tick_counter = remaining - consumed;
                         Status::Failure
                     } else {
-                        let child_status = tick_node(
+                        let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
                             child,
                             child_id,
                             states,
                             ctx,
+                            library,
                             action_handler,
                             condition_handler,
                             observer,
@@ -341,26 +1226,36 @@ where
                         .map(|v| v.is_truthy())
                         .unwrap_or(false);
                     if allowed {
-                        tick_node(
+                        tick_node_with_library::<F, _, _, _, _, _, _>(
                             child,
                             child_id,
                             states,
                             ctx,
+                            library,
                             action_handler,
                             condition_handler,
                             observer,
                         )
                     } else {
-                        reset_subtree(child, child_id, states);
+                        reset_subtree(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            observer,
+                        );
                         Status::Failure
                     }
                 }
                 Decorator::UntilSuccess => {
-                    let child_status = tick_node(
+                    let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
                         child,
                         child_id,
                         states,
                         ctx,
+                        library,
                         action_handler,
                         condition_handler,
                         observer,
@@ -368,22 +1263,39 @@ where
                     match child_status {
                         Status::Success => {
                             states[node_id].reset();
-                            reset_subtree(child, child_id, states);
+                            reset_subtree(
+                                child,
+                                child_id,
+                                states,
+                                ctx,
+                                library,
+                                action_handler,
+                                observer,
+                            );
                             Status::Success
                         }
                         Status::Failure => {
-                            reset_subtree(child, child_id, states);
+                            reset_subtree(
+                                child,
+                                child_id,
+                                states,
+                                ctx,
+                                library,
+                                action_handler,
+                                observer,
+                            );
                             Status::Running
                         }
                         Status::Running => Status::Running,
                     }
                 }
                 Decorator::UntilFail => {
-                    let child_status = tick_node(
+                    let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
                         child,
                         child_id,
                         states,
                         ctx,
+                        library,
                         action_handler,
                         condition_handler,
                         observer,
@@ -391,29 +1303,56 @@ where
                     match child_status {
                         Status::Failure => {
                             states[node_id].reset();
-                            reset_subtree(child, child_id, states);
+                            reset_subtree(
+                                child,
+                                child_id,
+                                states,
+                                ctx,
+                                library,
+                                action_handler,
+                                observer,
+                            );
                             Status::Failure
                         }
                         Status::Success => {
-                            reset_subtree(child, child_id, states);
+                            reset_subtree(
+                                child,
+                                child_id,
+                                states,
+                                ctx,
+                                library,
+                                action_handler,
+                                observer,
+                            );
                             Status::Running
                         }
                         Status::Running => Status::Running,
                     }
                 }
                 Decorator::Timeout(max_ticks) => {
-                    let elapsed = states[node_id].tick_counter.saturating_add(ctx.delta_ticks());
+                    let elapsed = states[node_id]
+                        .tick_counter
+                        .saturating_add(ctx.delta_ticks());
                     states[node_id].tick_counter = elapsed;
                     if elapsed >= *max_ticks {
                         states[node_id].reset();
-                        reset_subtree(child, child_id, states);
+                        reset_subtree(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            observer,
+                        );
                         Status::Failure
                     } else {
-                        let child_status = tick_node(
+                        let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
                             child,
                             child_id,
                             states,
                             ctx,
+                            library,
                             action_handler,
                             condition_handler,
                             observer,
@@ -425,11 +1364,12 @@ where
                     }
                 }
                 Decorator::ForceSuccess => {
-                    let child_status = tick_node(
+                    let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
                         child,
                         child_id,
                         states,
                         ctx,
+                        library,
                         action_handler,
                         condition_handler,
                         observer,
@@ -441,11 +1381,12 @@ where
                     }
                 }
                 Decorator::ForceFailure => {
-                    let child_status = tick_node(
+                    let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
                         child,
                         child_id,
                         states,
                         ctx,
+                        library,
                         action_handler,
                         condition_handler,
                         observer,
@@ -456,155 +1397,514 @@ where
                         Status::Failure
                     }
                 }
-            }
-        }
-        BehaviorNode::Action(action_id) => action_handler.execute(action_id, ctx),
-        BehaviorNode::Condition(condition_id) => {
-            if condition_handler.check(condition_id, ctx) {
-                Status::Success
-            } else {
-                Status::Failure
-            }
-        }
-        BehaviorNode::Wait(ticks) => {
-            if *ticks == 0 {
-                states[node_id].reset();
-                Status::Success
-            } else {
-                let elapsed = states[node_id].tick_counter.saturating_add(ctx.delta_ticks());
-                states[node_id].tick_counter = elapsed;
-                if elapsed >= *ticks {
-                    states[node_id].reset();
-                    Status::Success
-                } else {
-                    Status::Running
+                Decorator::Remap {
+                    on_success,
+                    on_failure,
+                } => {
+                    match tick_node_with_library::<F, _, _, _, _, _, _>(
+                        child,
+                        child_id,
+                        states,
+                        ctx,
+                        library,
+                        action_handler,
+                        condition_handler,
+                        observer,
+                    ) {
+                        Status::Success => *on_success,
+                        Status::Failure => *on_failure,
+                        Status::Running => Status::Running,
+                    }
                 }
-            }
-        }
-        BehaviorNode::UtilitySelector {
-            children,
-            utility_ids,
-        } => {
-            if children.is_empty() || children.len() != utility_ids.len() {
-                states[node_id].reset();
-                Status::Failure
-            } else if let Some(selected) = states[node_id].selected_child {
-                if selected >= children.len() {
-                    states[node_id].reset();
-                    Status::Failure
-                } else {
-                    let child_id = child_id_for_index(children, node_id, selected);
-                    let child_status = tick_node(
-                        &children[selected],
+                Decorator::RunningTimeout(max_ticks) => {
+                    let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                        child,
                         child_id,
                         states,
                         ctx,
+                        library,
                         action_handler,
                         condition_handler,
                         observer,
                     );
-                    if child_status != Status::Running {
+                    if child_status == Status::Running {
+                        let running = states[child_id].running_index();
+                        let elapsed = if states[node_id].selected_index() == Some(running) {
+                            states[node_id]
+                                .tick_counter
+                                .saturating_add(ctx.delta_ticks())
+                        } else {
+                            states[node_id].set_selected_index(Some(running));
+                            ctx.delta_ticks()
+                        };
+                        states[node_id].tick_counter = elapsed;
+                        if elapsed > *max_ticks {
+                            states[node_id].reset();
+                            reset_subtree(
+                                child,
+                                child_id,
+                                states,
+                                ctx,
+                                library,
+                                action_handler,
+                                observer,
+                            );
+                            Status::Failure
+                        } else {
+                            Status::Running
+                        }
+                    } else {
                         states[node_id].reset();
+                        child_status
                     }
-                    child_status
                 }
-            } else {
-                let mut best_idx = 0usize;
-                let mut best_score = f32::MIN;
-                for (i, utility_key) in utility_ids.iter().enumerate() {
-                    let score = ctx
-                        .blackboard()
-                        .get(*utility_key)
-                        .map(|v| v.to_score_f32())
-                        .unwrap_or(0.0);
-                    observer.on_utility_score(i, score);
-                    if score > best_score {
-                        best_score = score;
-                        best_idx = i;
+                Decorator::Yield => {
+                    let held = states[node_id]
+                        .extra
+                        .as_mut()
+                        .and_then(|e| e.yielded.take());
+                    if let Some(held) = held {
+                        states[node_id].reset();
+                        held
+                    } else {
+                        let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            condition_handler,
+                            observer,
+                        );
+                        if child_status.is_done() {
+                            states[node_id].extra_mut().yielded = Some(child_status);
+                        }
+                        Status::Running
                     }
                 }
-
-                states[node_id].selected_child = Some(best_idx);
-                let child_id = child_id_for_index(children, node_id, best_idx);
-                let child_status = tick_node(
-                    &children[best_idx],
-                    child_id,
-                    states,
-                    ctx,
-                    action_handler,
-                    condition_handler,
-                    observer,
-                );
-                if child_status != Status::Running {
-                    states[node_id].reset();
-                }
-                child_status
-            }
-        }
-        BehaviorNode::RandomSelector(children) => {
-            if children.is_empty() {
-                states[node_id].reset();
-                Status::Failure
-            } else {
-                let selected = match states[node_id].random_selection {
-                    Some(idx) if idx < children.len() => idx,
-                    _ => {
-                        let idx = (ctx.rng().next_u32() as usize) % children.len();
-                        states[node_id].random_selection = Some(idx);
-                        idx
+                Decorator::KeepRunning => {
+                    let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                        child,
+                        child_id,
+                        states,
+                        ctx,
+                        library,
+                        action_handler,
+                        condition_handler,
+                        observer,
+                    );
+                    if child_status.is_done() {
+                        reset_subtree(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            observer,
+                        );
                     }
-                };
-
-                let child_id = child_id_for_index(children, node_id, selected);
-                let child_status = tick_node(
-                    &children[selected],
-                    child_id,
-                    states,
+                    Status::Running
+                }
+                Decorator::Limit(max) => {
+                    // Completions survive ancestor resets, unlike `Repeat`'s passes.
+                    let completions = states[node_id].extra().map_or(0, |e| e.completions);
+                    if completions >= *max {
+                        Status::Failure
+                    } else {
+                        let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            condition_handler,
+                            observer,
+                        );
+                        if child_status.is_done() {
+                            states[node_id].extra_mut().completions = completions + 1;
+                        }
+                        child_status
+                    }
+                }
+                Decorator::Delay(delay_ticks) => {
+                    let elapsed = states[node_id].tick_counter;
+                    if elapsed < *delay_ticks {
+                        states[node_id].tick_counter = elapsed.saturating_add(ctx.delta_ticks());
+                        Status::Running
+                    } else {
+                        let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            condition_handler,
+                            observer,
+                        );
+                        if child_status.is_done() {
+                            states[node_id].reset();
+                        }
+                        child_status
+                    }
+                }
+                Decorator::Once => {
+                    if states[node_id].extra().is_some_and(|e| e.completions != 0) {
+                        Status::Success
+                    } else {
+                        let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            condition_handler,
+                            observer,
+                        );
+                        if child_status == Status::Success {
+                            states[node_id].extra_mut().completions = 1;
+                        }
+                        child_status
+                    }
+                }
+                Decorator::WaitUntil(key) => {
+                    let ready = states[node_id].selected_index().is_some()
+                        || ctx
+                            .blackboard()
+                            .get(*key)
+                            .map(|v| v.is_truthy())
+                            .unwrap_or(false);
+                    if ready {
+                        // Latch the release so the child keeps running if the key clears.
+                        states[node_id].set_selected_index(Some(0));
+                        let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            condition_handler,
+                            observer,
+                        );
+                        if child_status.is_done() {
+                            states[node_id].reset();
+                        }
+                        child_status
+                    } else {
+                        Status::Running
+                    }
+                }
+                Decorator::InterruptIf(key) => {
+                    let interrupted = ctx
+                        .blackboard()
+                        .get(*key)
+                        .map(|v| v.is_truthy())
+                        .unwrap_or(false);
+                    if interrupted {
+                        states[node_id].reset();
+                        reset_subtree(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            observer,
+                        );
+                        Status::Failure
+                    } else {
+                        tick_node_with_library::<F, _, _, _, _, _, _>(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            condition_handler,
+                            observer,
+                        )
+                    }
+                }
+                Decorator::Chance(permille) => {
+                    let roll = match states[node_id].random_index() {
+                        Some(roll) => roll,
+                        None => {
+                            let roll = (ctx.rng().next_u32() % 1000) as usize;
+                            states[node_id].set_random_index(Some(roll));
+                            roll
+                        }
+                    };
+                    if roll < *permille as usize {
+                        let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                            child,
+                            child_id,
+                            states,
+                            ctx,
+                            library,
+                            action_handler,
+                            condition_handler,
+                            observer,
+                        );
+                        if child_status.is_done() {
+                            states[node_id].reset();
+                        }
+                        child_status
+                    } else {
+                        states[node_id].reset();
+                        Status::Failure
+                    }
+                }
+            }
+        }
+        BehaviorNode::Action(action_id) => action_handler.execute(action_id, ctx),
+        BehaviorNode::Condition(condition_id) => {
+            if condition_handler.check(condition_id, ctx) {
+                Status::Success
+            } else {
+                Status::Failure
+            }
+        }
+        BehaviorNode::BlackboardCondition(key) => {
+            if ctx
+                .blackboard()
+                .get(*key)
+                .map(|v| v.is_truthy())
+                .unwrap_or(false)
+            {
+                Status::Success
+            } else {
+                Status::Failure
+            }
+        }
+        BehaviorNode::Compare { key, op, value } => {
+            if ctx
+                .blackboard()
+                .get(*key)
+                .is_some_and(|v| v.compare(*op, *value))
+            {
+                Status::Success
+            } else {
+                Status::Failure
+            }
+        }
+        BehaviorNode::SetBlackboard { key, value } => {
+            if ctx.blackboard_mut().set(*key, *value) {
+                observer.on_blackboard_write(*key, *value);
+                Status::Success
+            } else {
+                Status::Failure
+            }
+        }
+        BehaviorNode::ClearBlackboard(key) => {
+            ctx.blackboard_mut().remove(*key);
+            Status::Success
+        }
+        BehaviorNode::Wait(ticks) => {
+            if *ticks == 0 {
+                states[node_id].reset();
+                Status::Success
+            } else {
+                let elapsed = states[node_id]
+                    .tick_counter
+                    .saturating_add(ctx.delta_ticks());
+                states[node_id].tick_counter = elapsed;
+                if elapsed >= *ticks {
+                    states[node_id].reset();
+                    Status::Success
+                } else {
+                    Status::Running
+                }
+            }
+        }
+        BehaviorNode::WaitMillis(millis) => {
+            if *millis == 0 {
+                states[node_id].reset();
+                Status::Success
+            } else {
+                let elapsed = states[node_id]
+                    .tick_counter
+                    .saturating_add(ctx.delta_millis());
+                states[node_id].tick_counter = elapsed;
+                if elapsed >= *millis {
+                    states[node_id].reset();
+                    Status::Success
+                } else {
+                    Status::Running
+                }
+            }
+        }
+        BehaviorNode::SubtreeRef(id) => match library.and_then(|library| library.get(*id)) {
+            Some(_) if *ctx.subtree_depth_mut() >= MAX_SUBTREE_DEPTH => Status::Failure,
+            Some(subtree) => {
+                let mut nested = core::mem::take(&mut states[node_id].extra_mut().subtree_states);
+                let size = subtree_size(subtree);
+                if nested.len() != size {
+                    nested.clear();
+                    nested.resize(size, NodeState::default());
+                }
+                *ctx.subtree_depth_mut() += 1;
+                let status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                    subtree,
+                    0,
+                    &mut nested,
                     ctx,
+                    library,
                     action_handler,
                     condition_handler,
-                    observer,
+                    &mut NoOpObserver,
                 );
-                if child_status != Status::Running {
-                    states[node_id].reset();
+                *ctx.subtree_depth_mut() -= 1;
+                states[node_id].extra_mut().subtree_states = nested;
+                status
+            }
+            None => Status::Failure,
+        },
+        BehaviorNode::TimedSelector {
+            max_ticks,
+            primary,
+            fallback,
+        } => {
+            let primary_id = node_id + 1;
+            let fallback_id = primary_id + subtree_size(primary);
+            let mut use_fallback = states[node_id].selected_index().is_some();
+            if !use_fallback {
+                let elapsed = states[node_id]
+                    .tick_counter
+                    .saturating_add(ctx.delta_ticks());
+                states[node_id].tick_counter = elapsed;
+                if elapsed >= *max_ticks {
+                    reset_subtree(
+                        primary,
+                        primary_id,
+                        states,
+                        ctx,
+                        library,
+                        action_handler,
+                        observer,
+                    );
+                    states[node_id].set_selected_index(Some(1));
+                    use_fallback = true;
                 }
-                child_status
             }
+            let (child, child_id) = if use_fallback {
+                (fallback, fallback_id)
+            } else {
+                (primary, primary_id)
+            };
+            let status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                child,
+                child_id,
+                states,
+                ctx,
+                library,
+                action_handler,
+                condition_handler,
+                observer,
+            );
+            if status.is_done() {
+                states[node_id].reset();
+            }
+            status
         }
-        BehaviorNode::WeightedSelector { children, weights } => {
-            if children.is_empty() || children.len() != weights.len() {
+        BehaviorNode::Throttle(interval) => {
+            let ready = match states[node_id].extra().and_then(|e| e.last_success_tick) {
+                Some(last) => ctx.tick().saturating_sub(last) >= *interval as u64,
+                None => true,
+            };
+            if ready {
+                states[node_id].extra_mut().last_success_tick = Some(ctx.tick());
+                Status::Success
+            } else {
+                Status::Failure
+            }
+        }
+        BehaviorNode::WaitRandom { min, max } => {
+            let duration = match states[node_id].extra().and_then(|e| e.rolled_wait) {
+                Some(duration) => duration,
+                None => {
+                    let (low, high) = if min <= max {
+                        (*min, *max)
+                    } else {
+                        (*max, *min)
+                    };
+                    let span = (high - low) as u64 + 1;
+                    let duration = low + (ctx.rng().next_u32() as u64 % span) as u32;
+                    states[node_id].extra_mut().rolled_wait = Some(duration);
+                    duration
+                }
+            };
+            let elapsed = states[node_id]
+                .tick_counter
+                .saturating_add(ctx.delta_ticks());
+            states[node_id].tick_counter = elapsed;
+            if duration == 0 || elapsed >= duration {
+                states[node_id].reset();
+                Status::Success
+            } else {
+                Status::Running
+            }
+        }
+        BehaviorNode::UtilitySelector {
+            children,
+            utility_ids,
+        } => {
+            if children.is_empty() || children.len() != utility_ids.len() {
                 states[node_id].reset();
                 Status::Failure
+            } else if let Some(selected) = states[node_id].selected_index() {
+                if selected >= children.len() {
+                    states[node_id].reset();
+                    Status::Failure
+                } else {
+                    let child_id = child_id_for_index(children, node_id, selected);
+                    let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                        &children[selected],
+                        child_id,
+                        states,
+                        ctx,
+                        library,
+                        action_handler,
+                        condition_handler,
+                        observer,
+                    );
+                    if child_status != Status::Running {
+                        states[node_id].reset();
+                    }
+                    child_status
+                }
             } else {
-                let selected = match states[node_id].random_selection {
-                    Some(idx) if idx < children.len() => idx,
-                    _ => {
-                        let total_weight: u32 = weights.iter().copied().sum();
-                        if total_weight == 0 {
-                            states[node_id].reset();
-                            observer.on_exit(node_id, Status::Failure);
-                            return Status::Failure;
-                        }
-                        let mut roll = ctx.rng().next_u32() % total_weight;
-                        let mut idx = 0usize;
-                        for (i, weight) in weights.iter().enumerate() {
-                            if roll < *weight {
-                                idx = i;
-                                break;
-                            }
-                            roll = roll.saturating_sub(*weight);
-                        }
-                        states[node_id].random_selection = Some(idx);
-                        idx
+                let mut best_idx = 0usize;
+                let mut best_score: Option<F> = None;
+                for (i, utility_key) in utility_ids.iter().enumerate() {
+                    let score = ctx
+                        .blackboard()
+                        .get(*utility_key)
+                        .map(|v| F::from_f32(v.to_score_f32()))
+                        .unwrap_or(F::zero());
+                    observer.on_utility_score(i, score.to_f32());
+                    let better = match best_score {
+                        Some(best) => score > best,
+                        None => true,
+                    };
+                    if better {
+                        best_score = Some(score);
+                        best_idx = i;
                     }
-                };
+                }
 
-                let child_id = child_id_for_index(children, node_id, selected);
-                let child_status = tick_node(
-                    &children[selected],
+                states[node_id].set_selected_index(Some(best_idx));
+                let child_id = child_id_for_index(children, node_id, best_idx);
+                let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                    &children[best_idx],
                     child_id,
                     states,
                     ctx,
+                    library,
                     action_handler,
                     condition_handler,
                     observer,
@@ -615,37 +1915,236 @@ where
                 child_status
             }
         }
-    };
-
-    observer.on_exit(node_id, status);
-    status
-}
+        BehaviorNode::Reasoner { reasoner, children } => {
+            let selected = match states[node_id].selected_index() {
+                Some(idx) => Some(idx),
+                None if reasoner.actions.is_empty() => None,
+                None => {
+                    let previous = states[node_id].extra().and_then(|e| e.last_selection);
+                    let pick = with_scratch(ctx, |ctx, scratch| {
+                        let (blackboard, rng) = ctx.blackboard_and_rng();
+                        reasoner.select_with(
+                            blackboard,
+                            previous,
+                            rng,
+                            &mut scratch.scores,
+                            &mut scratch.indices,
+                        )
+                    });
+                    states[node_id].extra_mut().last_selection = Some(pick);
+                    Some(reasoner.actions[pick].action_id)
+                }
+            };
 
-#[cfg(test)]
-mod tests {
-    use alloc::boxed::Box;
-    use alloc::collections::BTreeMap;
-    use alloc::vec;
+            match selected {
+                Some(idx) if idx < children.len() => {
+                    states[node_id].set_selected_index(Some(idx));
+                    let child_id = child_id_for_index(children, node_id, idx);
+                    let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                        &children[idx],
+                        child_id,
+                        states,
+                        ctx,
+                        library,
+                        action_handler,
+                        condition_handler,
+                        observer,
+                    );
+                    if child_status != Status::Running {
+                        states[node_id].reset();
+                    }
+                    child_status
+                }
+                _ => {
+                    states[node_id].reset();
+                    Status::Failure
+                }
+            }
+        }
+        BehaviorNode::RandomSelector { children, seed } => {
+            if children.is_empty() {
+                states[node_id].reset();
+                Status::Failure
+            } else {
+                let selected = match states[node_id].random_index() {
+                    Some(idx) if idx < children.len() => idx,
+                    _ => {
+                        let idx = (seeded_roll(ctx, *seed) as usize) % children.len();
+                        states[node_id].set_random_index(Some(idx));
+                        idx
+                    }
+                };
+
+                let child_id = child_id_for_index(children, node_id, selected);
+                let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                    &children[selected],
+                    child_id,
+                    states,
+                    ctx,
+                    library,
+                    action_handler,
+                    condition_handler,
+                    observer,
+                );
+                if child_status != Status::Running {
+                    states[node_id].reset();
+                }
+                child_status
+            }
+        }
+        BehaviorNode::WeightedSelector {
+            children,
+            weights,
+            seed,
+        } => {
+            if children.is_empty() || children.len() != weights.len() {
+                states[node_id].reset();
+                Status::Failure
+            } else {
+                let selected = match states[node_id].random_index() {
+                    Some(idx) if idx < children.len() => idx,
+                    _ => {
+                        let total_weight: u32 = weights.iter().copied().sum();
+                        if total_weight == 0 {
+                            states[node_id].reset();
+                            states[node_id].last_status = Some(Status::Failure);
+                            observer.on_exit(node_id, Status::Failure);
+                            return Status::Failure;
+                        }
+                        let mut roll = seeded_roll(ctx, *seed) % total_weight;
+                        let mut idx = 0usize;
+                        for (i, weight) in weights.iter().enumerate() {
+                            if roll < *weight {
+                                idx = i;
+                                break;
+                            }
+                            roll = roll.saturating_sub(*weight);
+                        }
+                        states[node_id].set_random_index(Some(idx));
+                        idx
+                    }
+                };
+
+                let child_id = child_id_for_index(children, node_id, selected);
+                let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                    &children[selected],
+                    child_id,
+                    states,
+                    ctx,
+                    library,
+                    action_handler,
+                    condition_handler,
+                    observer,
+                );
+                if child_status != Status::Running {
+                    states[node_id].reset();
+                }
+                child_status
+            }
+        }
+        BehaviorNode::BlackboardWeightedSelector {
+            children,
+            weight_keys,
+            seed,
+        } => {
+            if children.is_empty() || children.len() != weight_keys.len() {
+                states[node_id].reset();
+                Status::Failure
+            } else {
+                let selected = match states[node_id].random_index() {
+                    Some(idx) if idx < children.len() => idx,
+                    _ => {
+                        let rolled = with_scratch(ctx, |ctx, scratch| {
+                            let weights = &mut scratch.scores;
+                            weights.clear();
+                            weights.extend(weight_keys.iter().enumerate().map(|(i, key)| {
+                                let weight = ctx
+                                    .blackboard()
+                                    .get(*key)
+                                    .map(|v| v.to_score_f32())
+                                    .filter(|w| *w > 0.0)
+                                    .unwrap_or(0.0);
+                                (i, weight)
+                            }));
+                            let total_weight: f32 = weights.iter().map(|(_, w)| w).sum();
+                            if total_weight <= 0.0 {
+                                return None;
+                            }
+                            let roll_01 =
+                                (seeded_roll(ctx, *seed) as f32) / ((u32::MAX as f32) + 1.0);
+                            let roll = roll_01 * total_weight;
+                            let mut cumulative = 0.0f32;
+                            let mut idx = children.len() - 1;
+                            for (i, weight) in weights.iter() {
+                                cumulative += *weight;
+                                if *weight > 0.0 && roll < cumulative {
+                                    idx = *i;
+                                    break;
+                                }
+                            }
+                            Some(idx)
+                        });
+                        let Some(idx) = rolled else {
+                            states[node_id].reset();
+                            states[node_id].last_status = Some(Status::Failure);
+                            observer.on_exit(node_id, Status::Failure);
+                            return Status::Failure;
+                        };
+                        states[node_id].set_random_index(Some(idx));
+                        idx
+                    }
+                };
+
+                let child_id = child_id_for_index(children, node_id, selected);
+                let child_status = tick_node_with_library::<F, _, _, _, _, _, _>(
+                    &children[selected],
+                    child_id,
+                    states,
+                    ctx,
+                    library,
+                    action_handler,
+                    condition_handler,
+                    observer,
+                );
+                if child_status != Status::Running {
+                    states[node_id].reset();
+                }
+                child_status
+            }
+        }
+    };
+
+    states[node_id].last_status = Some(status);
+    observer.on_exit(node_id, status);
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use alloc::collections::BTreeMap;
+    use alloc::vec;
     use alloc::vec::Vec;
     use rand_core::{Error, RngCore};
 
     use super::{assign_ids, tick_node, NodeState};
     use crate::{
-        ActionHandler, BehaviorNode, Blackboard, ConditionHandler, Context, Decorator,
-        NoOpObserver, ParallelPolicy, Status,
+        ActionHandler, BehaviorNode, Blackboard, BlackboardValue, CompareOp, ConditionHandler,
+        Context, Decorator, NoOpObserver, ObserverEvent, ParallelPolicy, RecordingObserver, Status,
     };
 
     #[derive(Default)]
     struct ScriptedActionHandler {
         scripted: BTreeMap<u32, Vec<Status>>,
         calls: Vec<u32>,
+        aborted: Vec<u32>,
     }
 
     impl ScriptedActionHandler {
         fn with_script(scripted: BTreeMap<u32, Vec<Status>>) -> Self {
             Self {
                 scripted,
-                calls: Vec::new(),
+                ..Self::default()
             }
         }
     }
@@ -663,6 +2162,10 @@ mod tests {
                 Status::Success
             }
         }
+
+        fn on_abort(&mut self, action: &u32, _ctx: &mut Context) {
+            self.aborted.push(*action);
+        }
     }
 
     #[derive(Default)]
@@ -748,834 +2251,2014 @@ mod tests {
         let mut bb = Blackboard::new();
         let mut actions = ScriptedActionHandler::default();
         let conditions = ScriptedConditionHandler::default();
-        let status = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Success);
+        assert_eq!(actions.calls, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn tick_sequence_first_failure() {
+        let node = BehaviorNode::Sequence(vec![
+            BehaviorNode::Action(1),
+            BehaviorNode::Action(2),
+            BehaviorNode::Action(3),
+        ]);
+        let mut script = BTreeMap::new();
+        script.insert(2, vec![Status::Failure]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Failure);
+        assert_eq!(actions.calls, vec![1, 2]);
+    }
+
+    #[test]
+    fn tick_sequence_resumes_running() {
+        let node = BehaviorNode::Sequence(vec![
+            BehaviorNode::Action(1),
+            BehaviorNode::Action(2),
+            BehaviorNode::Action(3),
+        ]);
+        let mut script = BTreeMap::new();
+        script.insert(2, vec![Status::Running, Status::Success]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+
+        let first = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        let second = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+
+        assert_eq!(first, Status::Running);
+        assert_eq!(second, Status::Success);
+        assert_eq!(actions.calls, vec![1, 2, 2, 3]);
+        assert_eq!(actions.calls.iter().filter(|a| **a == 1).count(), 1);
+    }
+
+    #[test]
+    fn tick_selector_first_success() {
+        let node = BehaviorNode::Selector(vec![BehaviorNode::Action(1), BehaviorNode::Action(2)]);
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Success);
+        assert_eq!(actions.calls, vec![1]);
+    }
+
+    #[test]
+    fn tick_selector_all_failure() {
+        let node = BehaviorNode::Selector(vec![BehaviorNode::Action(1), BehaviorNode::Action(2)]);
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Failure]);
+        script.insert(2, vec![Status::Failure]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Failure);
+        assert_eq!(actions.calls, vec![1, 2]);
+    }
+
+    #[test]
+    fn tick_selector_resumes_running() {
+        let node = BehaviorNode::Selector(vec![
+            BehaviorNode::Action(1),
+            BehaviorNode::Action(2),
+            BehaviorNode::Action(3),
+        ]);
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Failure]);
+        script.insert(2, vec![Status::Running, Status::Success]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+
+        let first = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        let second = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+
+        assert_eq!(first, Status::Running);
+        assert_eq!(second, Status::Success);
+        assert_eq!(actions.calls.iter().filter(|a| **a == 1).count(), 1);
+    }
+
+    #[test]
+    fn tick_parallel_require_all_success() {
+        let node = BehaviorNode::Parallel {
+            policy: ParallelPolicy::RequireAll,
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            short_circuit: false,
+        };
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Success);
+    }
+
+    #[test]
+    fn tick_parallel_require_all_one_failure() {
+        let node = BehaviorNode::Parallel {
+            policy: ParallelPolicy::RequireAll,
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            short_circuit: false,
+        };
+        let mut script = BTreeMap::new();
+        script.insert(2, vec![Status::Failure]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Failure);
+    }
+
+    #[test]
+    fn tick_parallel_require_one_success() {
+        let node = BehaviorNode::Parallel {
+            policy: ParallelPolicy::RequireOne,
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            short_circuit: false,
+        };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Failure]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Success);
+    }
+
+    #[test]
+    fn tick_parallel_require_n() {
+        let node_success = BehaviorNode::Parallel {
+            policy: ParallelPolicy::RequireN(2),
+            children: vec![
+                BehaviorNode::Action(1),
+                BehaviorNode::Action(2),
+                BehaviorNode::Action(3),
+            ],
+            short_circuit: false,
+        };
+        let mut script_success = BTreeMap::new();
+        script_success.insert(3, vec![Status::Failure]);
+        let mut actions_success = ScriptedActionHandler::with_script(script_success);
+        let mut states_success = states_for(&node_success);
+        let mut bb = Blackboard::new();
+        let conditions = ScriptedConditionHandler::default();
+        let status_success = tick_once(
+            &node_success,
+            &mut states_success,
+            &mut bb,
+            None,
+            &mut actions_success,
+            &conditions,
+        );
+        assert_eq!(status_success, Status::Success);
+
+        let node_failure = BehaviorNode::Parallel {
+            policy: ParallelPolicy::RequireN(3),
+            children: vec![
+                BehaviorNode::Action(1),
+                BehaviorNode::Action(2),
+                BehaviorNode::Action(3),
+            ],
+            short_circuit: false,
+        };
+        let mut script_failure = BTreeMap::new();
+        script_failure.insert(1, vec![Status::Failure]);
+        script_failure.insert(2, vec![Status::Failure]);
+        let mut actions_failure = ScriptedActionHandler::with_script(script_failure);
+        let mut states_failure = states_for(&node_failure);
+        let status_failure = tick_once(
+            &node_failure,
+            &mut states_failure,
+            &mut bb,
+            None,
+            &mut actions_failure,
+            &conditions,
+        );
+        assert_eq!(status_failure, Status::Failure);
+    }
+
+    #[test]
+    fn tick_decorator_inverter() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::Inverter,
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Success]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Failure);
+    }
+
+    #[test]
+    fn tick_decorator_repeat() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::Repeat(2),
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        let first = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        let second = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(first, Status::Running);
+        assert_eq!(second, Status::Success);
+    }
+
+    #[test]
+    fn tick_decorator_retry() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::Retry(3),
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Failure, Status::Failure, Status::Success]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
+        );
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
+        );
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Success
+        );
+    }
+
+    #[test]
+    fn tick_decorator_repeat_tolerant_within_tolerance() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::RepeatTolerant {
+                iterations: 3,
+                max_failures: 2,
+            },
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Success, Status::Failure, Status::Success]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+
+        let mut results = Vec::new();
+        for _ in 0..3 {
+            results.push(tick_once(
+                &node,
+                &mut states,
+                &mut bb,
+                None,
+                &mut actions,
+                &conditions,
+            ));
+        }
+        assert_eq!(
+            results,
+            vec![Status::Running, Status::Running, Status::Success]
+        );
+        assert_eq!(states[0].iteration_count, 0);
+        assert_eq!(states[0].extra().map(|e| e.failure_count), Some(0));
+    }
+
+    #[test]
+    fn tick_decorator_repeat_tolerant_exceeds_tolerance() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::RepeatTolerant {
+                iterations: 5,
+                max_failures: 2,
+            },
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Failure, Status::Success, Status::Failure]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+
+        let mut results = Vec::new();
+        for _ in 0..3 {
+            results.push(tick_once(
+                &node,
+                &mut states,
+                &mut bb,
+                None,
+                &mut actions,
+                &conditions,
+            ));
+        }
+        assert_eq!(
+            results,
+            vec![Status::Running, Status::Running, Status::Failure]
+        );
+        assert_eq!(actions.calls, vec![1, 1, 1]);
+    }
+
+    #[test]
+    fn tick_decorator_cooldown() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::Cooldown(2),
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Success
+        );
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Failure
+        );
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Failure
+        );
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Success
+        );
+    }
+
+    #[test]
+    fn tick_decorator_guard_pass() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::Guard(10),
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        bb.set_bool(10, true);
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Success
+        );
+        assert_eq!(actions.calls, vec![1]);
+    }
+
+    #[test]
+    fn tick_decorator_guard_fail() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::Guard(10),
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        bb.set_bool(10, false);
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Failure
+        );
+        assert!(actions.calls.is_empty());
+    }
+
+    #[test]
+    fn tick_decorator_until_success() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::UntilSuccess,
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Failure, Status::Failure, Status::Success]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
+        );
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
+        );
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Success
+        );
+    }
+
+    #[test]
+    fn tick_decorator_until_fail() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::UntilFail,
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Success, Status::Success, Status::Failure]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
+        );
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
+        );
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Failure
+        );
+    }
+
+    #[test]
+    fn tick_decorator_timeout() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::Timeout(2),
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Running, Status::Running, Status::Running]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
+        );
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Failure
+        );
+    }
+
+    #[test]
+    fn tick_decorator_force_success() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::ForceSuccess,
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Failure]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Success
+        );
+    }
+
+    #[test]
+    fn tick_decorator_force_failure() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::ForceFailure,
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Failure
+        );
+    }
+
+    #[test]
+    fn tick_decorator_wait_until() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::WaitUntil(10),
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Running, Status::Failure]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        bb.set_bool(10, false);
+
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
+        );
+        assert!(actions.calls.is_empty());
+
+        bb.set_bool(10, true);
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
+        );
+
+        bb.set_bool(10, false);
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Failure
+        );
+        assert_eq!(actions.calls, vec![1, 1]);
+
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
+        );
+        assert_eq!(actions.calls, vec![1, 1]);
+    }
+
+    #[test]
+    fn tick_decorator_chance_thresholds() {
+        let never = BehaviorNode::Decorator {
+            decorator: Decorator::Chance(0),
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let always = BehaviorNode::Decorator {
+            decorator: Decorator::Chance(1000),
+            child: Box::new(BehaviorNode::Action(2)),
+        };
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        let mut bb = Blackboard::new();
+        let mut rng = SeqRng::new(vec![0, 999]);
+
+        let mut states = states_for(&never);
+        for _ in 0..2 {
+            assert_eq!(
+                tick_once(
+                    &never,
+                    &mut states,
+                    &mut bb,
+                    Some(&mut rng),
+                    &mut actions,
+                    &conditions
+                ),
+                Status::Failure
+            );
+        }
+
+        let mut states = states_for(&always);
+        for _ in 0..2 {
+            assert_eq!(
+                tick_once(
+                    &always,
+                    &mut states,
+                    &mut bb,
+                    Some(&mut rng),
+                    &mut actions,
+                    &conditions
+                ),
+                Status::Success
+            );
+        }
+        assert_eq!(actions.calls, vec![2, 2]);
+    }
+
+    #[test]
+    fn tick_decorator_chance_keeps_roll_while_running() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::Chance(500),
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Running, Status::Success]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let mut rng = SeqRng::new(vec![100, 900]);
+
+        assert_eq!(
+            tick_once(
+                &node,
+                &mut states,
+                &mut bb,
+                Some(&mut rng),
+                &mut actions,
+                &conditions
+            ),
+            Status::Running
+        );
+        assert_eq!(
+            tick_once(
+                &node,
+                &mut states,
+                &mut bb,
+                Some(&mut rng),
+                &mut actions,
+                &conditions
+            ),
+            Status::Success
+        );
+        assert_eq!(
+            tick_once(
+                &node,
+                &mut states,
+                &mut bb,
+                Some(&mut rng),
+                &mut actions,
+                &conditions
+            ),
+            Status::Failure
+        );
+        assert_eq!(actions.calls, vec![1, 1]);
+    }
+
+    #[test]
+    fn tick_wait_counts_ticks() {
+        let node = BehaviorNode::Wait(3);
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
+        );
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
+        );
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Success
         );
-        assert_eq!(status, Status::Success);
-        assert_eq!(actions.calls, vec![1, 2, 3]);
     }
 
     #[test]
-    fn tick_sequence_first_failure() {
-        let node = BehaviorNode::Sequence(vec![
-            BehaviorNode::Action(1),
-            BehaviorNode::Action(2),
-            BehaviorNode::Action(3),
-        ]);
-        let mut script = BTreeMap::new();
-        script.insert(2, vec![Status::Failure]);
-        let mut actions = ScriptedActionHandler::with_script(script);
-        let conditions = ScriptedConditionHandler::default();
+    fn tick_action_delegates() {
+        let node = BehaviorNode::Action(5);
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
-        let status = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
-        );
-        assert_eq!(status, Status::Failure);
-        assert_eq!(actions.calls, vec![1, 2]);
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Success);
+        assert_eq!(actions.calls, vec![5]);
     }
 
     #[test]
-    fn tick_sequence_resumes_running() {
-        let node = BehaviorNode::Sequence(vec![
-            BehaviorNode::Action(1),
-            BehaviorNode::Action(2),
-            BehaviorNode::Action(3),
-        ]);
-        let mut script = BTreeMap::new();
-        script.insert(2, vec![Status::Running, Status::Success]);
-        let mut actions = ScriptedActionHandler::with_script(script);
-        let conditions = ScriptedConditionHandler::default();
+    fn tick_condition_true() {
+        let node = BehaviorNode::Condition(10);
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
-
-        let first = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
-        );
-        let second = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
-        );
-
-        assert_eq!(first, Status::Running);
-        assert_eq!(second, Status::Success);
-        assert_eq!(actions.calls, vec![1, 2, 2, 3]);
-        assert_eq!(actions.calls.iter().filter(|a| **a == 1).count(), 1);
+        let mut actions = ScriptedActionHandler::default();
+        let mut conditions = ScriptedConditionHandler::default();
+        conditions.values.insert(10, true);
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Success);
     }
 
     #[test]
-    fn tick_selector_first_success() {
-        let node = BehaviorNode::Selector(vec![BehaviorNode::Action(1), BehaviorNode::Action(2)]);
+    fn tick_condition_false() {
+        let node = BehaviorNode::Condition(10);
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
         let mut actions = ScriptedActionHandler::default();
-        let conditions = ScriptedConditionHandler::default();
-        let status = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
-        );
-        assert_eq!(status, Status::Success);
-        assert_eq!(actions.calls, vec![1]);
+        let mut conditions = ScriptedConditionHandler::default();
+        conditions.values.insert(10, false);
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Failure);
     }
 
     #[test]
-    fn tick_selector_all_failure() {
-        let node = BehaviorNode::Selector(vec![BehaviorNode::Action(1), BehaviorNode::Action(2)]);
+    fn tick_random_selector_persists_running() {
+        let node = BehaviorNode::RandomSelector {
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            seed: None,
+        };
         let mut script = BTreeMap::new();
-        script.insert(1, vec![Status::Failure]);
-        script.insert(2, vec![Status::Failure]);
+        script.insert(1, vec![Status::Running, Status::Success]);
+        script.insert(2, vec![Status::Success]);
         let mut actions = ScriptedActionHandler::with_script(script);
         let conditions = ScriptedConditionHandler::default();
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
+        let mut rng = SeqRng::new(vec![0, 1]);
 
-        let status = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
+        assert_eq!(
+            tick_once(
+                &node,
+                &mut states,
+                &mut bb,
+                Some(&mut rng),
+                &mut actions,
+                &conditions
+            ),
+            Status::Running
         );
-        assert_eq!(status, Status::Failure);
-        assert_eq!(actions.calls, vec![1, 2]);
+        assert_eq!(
+            tick_once(
+                &node,
+                &mut states,
+                &mut bb,
+                Some(&mut rng),
+                &mut actions,
+                &conditions
+            ),
+            Status::Success
+        );
+        assert_eq!(actions.calls, vec![1, 1]);
     }
 
     #[test]
-    fn tick_selector_resumes_running() {
-        let node = BehaviorNode::Selector(vec![
-            BehaviorNode::Action(1),
-            BehaviorNode::Action(2),
-            BehaviorNode::Action(3),
-        ]);
-        let mut script = BTreeMap::new();
-        script.insert(1, vec![Status::Failure]);
-        script.insert(2, vec![Status::Running, Status::Success]);
-        let mut actions = ScriptedActionHandler::with_script(script);
-        let conditions = ScriptedConditionHandler::default();
+    fn tick_weighted_selector_respects_weights() {
+        let node = BehaviorNode::WeightedSelector {
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            weights: vec![1, 9],
+            seed: None,
+        };
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        let mut rng = SeqRng::new(vec![0, 9]);
 
-        let first = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
+        assert_eq!(
+            tick_once(
+                &node,
+                &mut states,
+                &mut bb,
+                Some(&mut rng),
+                &mut actions,
+                &conditions
+            ),
+            Status::Success
         );
-        let second = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
+        assert_eq!(
+            tick_once(
+                &node,
+                &mut states,
+                &mut bb,
+                Some(&mut rng),
+                &mut actions,
+                &conditions
+            ),
+            Status::Success
         );
-
-        assert_eq!(first, Status::Running);
-        assert_eq!(second, Status::Success);
-        assert_eq!(actions.calls.iter().filter(|a| **a == 1).count(), 1);
+        assert_eq!(actions.calls, vec![1, 2]);
     }
 
     #[test]
-    fn tick_parallel_require_all_success() {
-        let node = BehaviorNode::Parallel {
-            policy: ParallelPolicy::RequireAll,
+    fn tick_blackboard_weighted_selector_follows_keys() {
+        let node = BehaviorNode::BlackboardWeightedSelector {
             children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            weight_keys: vec![10, 11],
+            seed: None,
         };
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
         let mut actions = ScriptedActionHandler::default();
         let conditions = ScriptedConditionHandler::default();
-        let status = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
+        let mut rng = SeqRng::new(vec![u32::MAX / 2]);
+
+        bb.set_float(10, 3.0);
+        bb.set_float(11, 0.0);
+        assert_eq!(
+            tick_once(
+                &node,
+                &mut states,
+                &mut bb,
+                Some(&mut rng),
+                &mut actions,
+                &conditions
+            ),
+            Status::Success
         );
-        assert_eq!(status, Status::Success);
+
+        bb.set_float(10, -1.0);
+        bb.set_float(11, 2.0);
+        assert_eq!(
+            tick_once(
+                &node,
+                &mut states,
+                &mut bb,
+                Some(&mut rng),
+                &mut actions,
+                &conditions
+            ),
+            Status::Success
+        );
+        assert_eq!(actions.calls, vec![1, 2]);
     }
 
     #[test]
-    fn tick_parallel_require_all_one_failure() {
-        let node = BehaviorNode::Parallel {
-            policy: ParallelPolicy::RequireAll,
+    fn tick_blackboard_weighted_selector_zero_total_fails() {
+        let node = BehaviorNode::BlackboardWeightedSelector {
             children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            weight_keys: vec![10, 11],
+            seed: None,
         };
-        let mut script = BTreeMap::new();
-        script.insert(2, vec![Status::Failure]);
-        let mut actions = ScriptedActionHandler::with_script(script);
-        let conditions = ScriptedConditionHandler::default();
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
-        let status = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        let mut rng = SeqRng::new(vec![0]);
+        bb.set_int(10, 0);
+
+        assert_eq!(
+            tick_once(
+                &node,
+                &mut states,
+                &mut bb,
+                Some(&mut rng),
+                &mut actions,
+                &conditions
+            ),
+            Status::Failure
         );
-        assert_eq!(status, Status::Failure);
+        assert!(actions.calls.is_empty());
     }
 
     #[test]
-    fn tick_parallel_require_one_success() {
-        let node = BehaviorNode::Parallel {
-            policy: ParallelPolicy::RequireOne,
-            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
-        };
-        let mut script = BTreeMap::new();
-        script.insert(1, vec![Status::Failure]);
-        let mut actions = ScriptedActionHandler::with_script(script);
-        let conditions = ScriptedConditionHandler::default();
+    fn tick_reactive_selector_preempts_lower_branch() {
+        let node = BehaviorNode::ReactiveSelector(vec![
+            BehaviorNode::Sequence(vec![BehaviorNode::Condition(1), BehaviorNode::Action(1)]),
+            BehaviorNode::Wait(5),
+        ]);
+        let wait_id = 4;
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
-        let status = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Running]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let mut conditions = ScriptedConditionHandler::default();
+
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
         );
-        assert_eq!(status, Status::Success);
+        assert_eq!(states[wait_id].tick_counter, 1);
+
+        conditions.values.insert(1, true);
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
+        );
+        assert_eq!(actions.calls, vec![1]);
+        assert_eq!(states[wait_id].tick_counter, 0);
+        assert_eq!(states[0].selected_child, Some(0));
     }
 
     #[test]
-    fn tick_parallel_require_n() {
-        let node_success = BehaviorNode::Parallel {
-            policy: ParallelPolicy::RequireN(2),
-            children: vec![
+    fn tick_decorator_interrupt_if_resets_running_child() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::InterruptIf(7),
+            child: Box::new(BehaviorNode::Sequence(vec![
                 BehaviorNode::Action(1),
-                BehaviorNode::Action(2),
-                BehaviorNode::Action(3),
-            ],
+                BehaviorNode::Wait(5),
+            ])),
         };
-        let mut script_success = BTreeMap::new();
-        script_success.insert(3, vec![Status::Failure]);
-        let mut actions_success = ScriptedActionHandler::with_script(script_success);
-        let mut states_success = states_for(&node_success);
+        let mut states = states_for(&node);
         let mut bb = Blackboard::new();
+        let mut actions = ScriptedActionHandler::default();
         let conditions = ScriptedConditionHandler::default();
-        let status_success = tick_once(
-            &node_success,
-            &mut states_success,
-            &mut bb,
-            None,
-            &mut actions_success,
-            &conditions,
-        );
-        assert_eq!(status_success, Status::Success);
 
-        let node_failure = BehaviorNode::Parallel {
-            policy: ParallelPolicy::RequireN(3),
-            children: vec![
-                BehaviorNode::Action(1),
-                BehaviorNode::Action(2),
-                BehaviorNode::Action(3),
-            ],
-        };
-        let mut script_failure = BTreeMap::new();
-        script_failure.insert(1, vec![Status::Failure]);
-        script_failure.insert(2, vec![Status::Failure]);
-        let mut actions_failure = ScriptedActionHandler::with_script(script_failure);
-        let mut states_failure = states_for(&node_failure);
-        let status_failure = tick_once(
-            &node_failure,
-            &mut states_failure,
-            &mut bb,
-            None,
-            &mut actions_failure,
-            &conditions,
+        for _ in 0..2 {
+            assert_eq!(
+                tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+                Status::Running
+            );
+        }
+        assert_eq!(states[1].running_child, 1);
+        assert_eq!(states[3].tick_counter, 2);
+
+        bb.set_bool(7, true);
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Failure
         );
-        assert_eq!(status_failure, Status::Failure);
+        assert_eq!(states[1].running_child, 0);
+        assert_eq!(states[3].tick_counter, 0);
+        assert_eq!(actions.calls, vec![1]);
     }
 
     #[test]
-    fn tick_decorator_inverter() {
-        let node = BehaviorNode::Decorator {
-            decorator: Decorator::Inverter,
-            child: Box::new(BehaviorNode::Action(1)),
+    fn tick_blackboard_condition_skips_handler() {
+        struct PanickingConditions;
+
+        impl ConditionHandler<u32> for PanickingConditions {
+            fn check(&self, _condition: &u32, _ctx: &Context) -> bool {
+                panic!("condition handler must not be called");
+            }
+        }
+
+        let node = BehaviorNode::BlackboardCondition(3);
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let mut actions = ScriptedActionHandler::default();
+        let mut observer = NoOpObserver;
+
+        for (value, expected) in [(true, Status::Success), (false, Status::Failure)] {
+            bb.set_bool(3, value);
+            let mut ctx = Context::new(1, 1, &mut bb, None);
+            let status = tick_node(
+                &node,
+                0,
+                &mut states,
+                &mut ctx,
+                &mut actions,
+                &PanickingConditions,
+                &mut observer,
+            );
+            assert_eq!(status, expected);
+        }
+    }
+
+    #[test]
+    fn tick_set_blackboard_writes_value() {
+        let node = BehaviorNode::SetBlackboard {
+            key: 4,
+            value: BlackboardValue::Int(12),
         };
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
-        let mut script = BTreeMap::new();
-        script.insert(1, vec![Status::Success]);
-        let mut actions = ScriptedActionHandler::with_script(script);
+        let mut actions = ScriptedActionHandler::default();
         let conditions = ScriptedConditionHandler::default();
-        let status = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Success
         );
-        assert_eq!(status, Status::Failure);
+        assert_eq!(bb.get_int(4), Some(12));
     }
 
     #[test]
-    fn tick_decorator_repeat() {
-        let node = BehaviorNode::Decorator {
-            decorator: Decorator::Repeat(2),
-            child: Box::new(BehaviorNode::Action(1)),
-        };
+    fn tick_clear_blackboard_removes_key() {
+        let node = BehaviorNode::ClearBlackboard(4);
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
+        bb.set_bool(4, true);
         let mut actions = ScriptedActionHandler::default();
         let conditions = ScriptedConditionHandler::default();
-        let first = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
-        );
-        let second = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Success
         );
-        assert_eq!(first, Status::Running);
-        assert_eq!(second, Status::Success);
+        assert!(!bb.has(4));
     }
 
     #[test]
-    fn tick_decorator_retry() {
-        let node = BehaviorNode::Decorator {
-            decorator: Decorator::Retry(3),
-            child: Box::new(BehaviorNode::Action(1)),
+    fn tick_shuffled_selector_follows_rolled_order() {
+        let node = BehaviorNode::ShuffledSelector {
+            children: vec![
+                BehaviorNode::Action(1),
+                BehaviorNode::Action(2),
+                BehaviorNode::Action(3),
+            ],
+            seed: None,
         };
         let mut script = BTreeMap::new();
-        script.insert(1, vec![Status::Failure, Status::Failure, Status::Success]);
+        script.insert(2, vec![Status::Failure]);
+        script.insert(3, vec![Status::Running, Status::Success]);
         let mut actions = ScriptedActionHandler::with_script(script);
         let conditions = ScriptedConditionHandler::default();
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
+        // Swaps (2, 0) then (1, 0), producing the visit order [2, 3, 1].
+        let mut rng = SeqRng::new(vec![0, 0]);
+
         assert_eq!(
             tick_once(
                 &node,
                 &mut states,
                 &mut bb,
-                None,
-                &mut actions,
-                &conditions
-            ),
-            Status::Running
-        );
-        assert_eq!(
-            tick_once(
-                &node,
-                &mut states,
-                &mut bb,
-                None,
+                Some(&mut rng),
                 &mut actions,
                 &conditions
             ),
             Status::Running
         );
+        assert_eq!(states[0].extra().unwrap().shuffle_order, vec![1, 2, 0]);
         assert_eq!(
             tick_once(
                 &node,
                 &mut states,
                 &mut bb,
-                None,
+                Some(&mut rng),
                 &mut actions,
                 &conditions
             ),
             Status::Success
         );
+        assert_eq!(actions.calls, vec![2, 3, 3]);
+        assert!(states[0].extra().unwrap().shuffle_order.is_empty());
     }
 
     #[test]
-    fn tick_decorator_cooldown() {
-        let node = BehaviorNode::Decorator {
-            decorator: Decorator::Cooldown(2),
-            child: Box::new(BehaviorNode::Action(1)),
+    fn tick_shuffled_sequence_runs_all_in_rolled_order() {
+        let node = BehaviorNode::ShuffledSequence {
+            children: vec![
+                BehaviorNode::Action(1),
+                BehaviorNode::Action(2),
+                BehaviorNode::Action(3),
+            ],
+            seed: None,
         };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Running, Status::Success]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
-        let mut actions = ScriptedActionHandler::default();
-        let conditions = ScriptedConditionHandler::default();
+        // Swaps (2, 2) then (1, 0), producing the visit order [2, 1, 3].
+        let mut rng = SeqRng::new(vec![2, 0]);
 
         assert_eq!(
             tick_once(
                 &node,
                 &mut states,
                 &mut bb,
-                None,
+                Some(&mut rng),
                 &mut actions,
                 &conditions
             ),
-            Status::Success
+            Status::Running
         );
         assert_eq!(
             tick_once(
                 &node,
                 &mut states,
                 &mut bb,
-                None,
+                Some(&mut rng),
                 &mut actions,
                 &conditions
             ),
-            Status::Failure
+            Status::Success
         );
+        assert_eq!(actions.calls, vec![2, 1, 1, 3]);
+    }
+
+    #[test]
+    fn tick_shuffled_sequence_fails_on_first_failure() {
+        let node = BehaviorNode::ShuffledSequence {
+            children: vec![
+                BehaviorNode::Action(1),
+                BehaviorNode::Action(2),
+                BehaviorNode::Action(3),
+            ],
+            seed: None,
+        };
+        let mut script = BTreeMap::new();
+        script.insert(2, vec![Status::Failure]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let mut rng = SeqRng::new(vec![2, 0]);
+
         assert_eq!(
             tick_once(
                 &node,
                 &mut states,
                 &mut bb,
-                None,
+                Some(&mut rng),
                 &mut actions,
                 &conditions
             ),
             Status::Failure
         );
-        assert_eq!(
-            tick_once(
+        assert_eq!(actions.calls, vec![2]);
+    }
+
+    #[test]
+    fn tick_interrupted_action_receives_on_abort() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::InterruptIf(7),
+            child: Box::new(BehaviorNode::Sequence(vec![
+                BehaviorNode::Action(1),
+                BehaviorNode::Action(2),
+            ])),
+        };
+        let mut script = BTreeMap::new();
+        script.insert(2, vec![Status::Running, Status::Running]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+
+        assert_eq!(
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+            Status::Running
+        );
+        assert!(actions.aborted.is_empty());
+
+        bb.set_bool(7, true);
+        for _ in 0..2 {
+            assert_eq!(
+                tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions),
+                Status::Failure
+            );
+        }
+        assert_eq!(actions.aborted, vec![2]);
+    }
+
+    #[test]
+    fn tick_reasoner_latches_running_child() {
+        use crate::utility::{
+            ClampMode, Consideration, Reasoner, ResponseCurve, SelectionMethod, UtilityAction,
+        };
+
+        let action = |action_id: usize, input_key: u32| UtilityAction {
+            action_id,
+            considerations: vec![Consideration {
+                input_key,
+                curve: ResponseCurve::Linear {
+                    slope: 1.0,
+                    offset: 0.0,
+                },
+                weight: 1.0,
+                input_min: 0.0,
+                input_max: 1.0,
+                clamp_mode: ClampMode::Clamp,
+                invert: false,
+                bias: 0.0,
+                clamp_output: false,
+            }],
+            weight: 1.0,
+            momentum: 0.0,
+        };
+        let node = BehaviorNode::Reasoner {
+            reasoner: Reasoner {
+                actions: vec![action(0, 1), action(1, 2)],
+                selection_method: SelectionMethod::HighestScore,
+            },
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+        };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Running, Status::Success]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        bb.set_float(1, 0.9);
+        bb.set_float(2, 0.1);
+
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Running);
+
+        bb.set_float(1, 0.1);
+        bb.set_float(2, 0.9);
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Success);
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Success);
+        assert_eq!(actions.calls, vec![1, 1, 2]);
+    }
+
+    #[test]
+    fn tick_wait_millis_accumulates_delta() {
+        let node = BehaviorNode::WaitMillis(1000);
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        let mut observer = NoOpObserver;
+
+        let mut statuses = Vec::new();
+        for delta in [400, 700] {
+            let mut ctx = Context::new(1, 1, &mut bb, None).with_delta_millis(delta);
+            statuses.push(tick_node(
+                &node,
+                0,
+                &mut states,
+                &mut ctx,
+                &mut actions,
+                &conditions,
+                &mut observer,
+            ));
+        }
+        assert_eq!(statuses, vec![Status::Running, Status::Success]);
+
+        let instant = BehaviorNode::WaitMillis(0);
+        let mut states = states_for(&instant);
+        let status = tick_once(
+            &instant,
+            &mut states,
+            &mut bb,
+            None,
+            &mut actions,
+            &conditions,
+        );
+        assert_eq!(status, Status::Success);
+    }
+
+    #[test]
+    fn tick_wait_random_uses_rolled_duration() {
+        let node = BehaviorNode::WaitRandom { min: 2, max: 5 };
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        // 5 % 4 == 1, so the wait lasts 2 + 1 = 3 ticks.
+        let mut rng = SeqRng::new(vec![5]);
+
+        let mut statuses = Vec::new();
+        for _ in 0..3 {
+            statuses.push(tick_once(
                 &node,
                 &mut states,
                 &mut bb,
-                None,
+                Some(&mut rng),
                 &mut actions,
-                &conditions
-            ),
-            Status::Success
+                &conditions,
+            ));
+        }
+        assert_eq!(
+            statuses,
+            vec![Status::Running, Status::Running, Status::Success]
         );
+        assert_eq!(rng.idx, 1);
+        assert_eq!(states[0].extra().unwrap().rolled_wait, None);
     }
 
     #[test]
-    fn tick_decorator_guard_pass() {
-        let node = BehaviorNode::Decorator {
-            decorator: Decorator::Guard(10),
-            child: Box::new(BehaviorNode::Action(1)),
-        };
+    fn tick_throttle_succeeds_once_per_interval() {
+        let node = BehaviorNode::Throttle(3);
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
-        bb.set_bool(10, true);
         let mut actions = ScriptedActionHandler::default();
         let conditions = ScriptedConditionHandler::default();
-        assert_eq!(
-            tick_once(
+        let mut observer = NoOpObserver;
+
+        let mut statuses = Vec::new();
+        for tick in 1..=7 {
+            let mut ctx = Context::new(tick, 1, &mut bb, None);
+            statuses.push(tick_node(
                 &node,
+                0,
                 &mut states,
-                &mut bb,
-                None,
+                &mut ctx,
                 &mut actions,
-                &conditions
-            ),
-            Status::Success
+                &conditions,
+                &mut observer,
+            ));
+        }
+        assert_eq!(
+            statuses,
+            vec![
+                Status::Success,
+                Status::Failure,
+                Status::Failure,
+                Status::Success,
+                Status::Failure,
+                Status::Failure,
+                Status::Success,
+            ]
         );
+    }
+
+    #[test]
+    fn tick_throttle_survives_parent_reset() {
+        let node =
+            BehaviorNode::Sequence(vec![BehaviorNode::Throttle(10), BehaviorNode::Action(1)]);
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+
+        let first = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        let second = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!((first, second), (Status::Success, Status::Failure));
         assert_eq!(actions.calls, vec![1]);
     }
 
     #[test]
-    fn tick_decorator_guard_fail() {
+    fn tick_decorator_yield_holds_result_one_tick() {
         let node = BehaviorNode::Decorator {
-            decorator: Decorator::Guard(10),
+            decorator: Decorator::Yield,
             child: Box::new(BehaviorNode::Action(1)),
         };
-        let mut states = states_for(&node);
-        let mut bb = Blackboard::new();
-        bb.set_bool(10, false);
         let mut actions = ScriptedActionHandler::default();
         let conditions = ScriptedConditionHandler::default();
-        assert_eq!(
-            tick_once(
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+
+        let mut statuses = Vec::new();
+        for _ in 0..4 {
+            statuses.push(tick_once(
                 &node,
                 &mut states,
                 &mut bb,
                 None,
                 &mut actions,
-                &conditions
-            ),
-            Status::Failure
+                &conditions,
+            ));
+        }
+        assert_eq!(
+            statuses,
+            vec![
+                Status::Running,
+                Status::Success,
+                Status::Running,
+                Status::Success
+            ]
         );
-        assert!(actions.calls.is_empty());
+        assert_eq!(actions.calls, vec![1, 1]);
     }
 
     #[test]
-    fn tick_decorator_until_success() {
+    fn tick_decorator_keep_running_loops_child() {
         let node = BehaviorNode::Decorator {
-            decorator: Decorator::UntilSuccess,
+            decorator: Decorator::KeepRunning,
+            child: Box::new(BehaviorNode::Sequence(vec![
+                BehaviorNode::Action(1),
+                BehaviorNode::Action(2),
+            ])),
+        };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Success, Status::Failure, Status::Running]);
+        script.insert(2, vec![Status::Success]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+
+        for _ in 0..4 {
+            let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+            assert_eq!(status, Status::Running);
+        }
+        assert_eq!(actions.calls, vec![1, 2, 1, 1, 1, 2]);
+    }
+
+    #[test]
+    fn tick_decorator_limit_caps_activations() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::Limit(2),
             child: Box::new(BehaviorNode::Action(1)),
         };
         let mut script = BTreeMap::new();
-        script.insert(1, vec![Status::Failure, Status::Failure, Status::Success]);
+        script.insert(1, vec![Status::Running, Status::Success, Status::Failure]);
         let mut actions = ScriptedActionHandler::with_script(script);
         let conditions = ScriptedConditionHandler::default();
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
-        assert_eq!(
-            tick_once(
-                &node,
-                &mut states,
-                &mut bb,
-                None,
-                &mut actions,
-                &conditions
-            ),
-            Status::Running
-        );
-        assert_eq!(
-            tick_once(
+
+        let mut statuses = Vec::new();
+        for _ in 0..4 {
+            statuses.push(tick_once(
                 &node,
                 &mut states,
                 &mut bb,
                 None,
                 &mut actions,
-                &conditions
-            ),
-            Status::Running
-        );
+                &conditions,
+            ));
+        }
         assert_eq!(
-            tick_once(
-                &node,
-                &mut states,
-                &mut bb,
-                None,
-                &mut actions,
-                &conditions
-            ),
-            Status::Success
+            statuses,
+            vec![
+                Status::Running,
+                Status::Success,
+                Status::Failure,
+                Status::Failure
+            ]
         );
+        assert_eq!(actions.calls, vec![1, 1, 1]);
     }
 
     #[test]
-    fn tick_decorator_until_fail() {
+    fn tick_decorator_limit_survives_looping_parent() {
         let node = BehaviorNode::Decorator {
-            decorator: Decorator::UntilFail,
-            child: Box::new(BehaviorNode::Action(1)),
+            decorator: Decorator::Repeat(5),
+            child: Box::new(BehaviorNode::Decorator {
+                decorator: Decorator::ForceSuccess,
+                child: Box::new(BehaviorNode::Decorator {
+                    decorator: Decorator::Limit(2),
+                    child: Box::new(BehaviorNode::Action(1)),
+                }),
+            }),
+        };
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+
+        for _ in 0..5 {
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        }
+        assert_eq!(actions.calls, vec![1, 1]);
+
+        states[2] = NodeState::default();
+        tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(actions.calls, vec![1, 1, 1]);
+    }
+
+    #[test]
+    fn tick_parallel_require_weight_heavy_child_alone() {
+        let node = BehaviorNode::Parallel {
+            policy: ParallelPolicy::RequireWeight {
+                threshold: 5,
+                weights: vec![1, 5, 1],
+            },
+            children: vec![
+                BehaviorNode::Action(1),
+                BehaviorNode::Action(2),
+                BehaviorNode::Action(3),
+            ],
+            short_circuit: false,
         };
         let mut script = BTreeMap::new();
-        script.insert(1, vec![Status::Success, Status::Success, Status::Failure]);
+        script.insert(1, vec![Status::Failure]);
+        script.insert(3, vec![Status::Running]);
         let mut actions = ScriptedActionHandler::with_script(script);
         let conditions = ScriptedConditionHandler::default();
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Success);
 
-        assert_eq!(
-            tick_once(
-                &node,
-                &mut states,
-                &mut bb,
-                None,
-                &mut actions,
-                &conditions
-            ),
-            Status::Running
+        let mut script = BTreeMap::new();
+        script.insert(2, vec![Status::Failure]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let mut states = states_for(&node);
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Failure);
+    }
+
+    #[test]
+    fn tick_parallel_short_circuit_skips_later_children() {
+        let node = BehaviorNode::Parallel {
+            policy: ParallelPolicy::RequireOne,
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            short_circuit: true,
+        };
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Success);
+        assert_eq!(actions.calls, vec![1]);
+
+        let eager = BehaviorNode::Parallel {
+            policy: ParallelPolicy::RequireOne,
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            short_circuit: false,
+        };
+        let mut actions = ScriptedActionHandler::default();
+        let mut states = states_for(&eager);
+        tick_once(
+            &eager,
+            &mut states,
+            &mut bb,
+            None,
+            &mut actions,
+            &conditions,
         );
-        assert_eq!(
-            tick_once(
+        assert_eq!(actions.calls, vec![1, 2]);
+    }
+
+    #[test]
+    fn tick_decorator_delay_waits_before_child() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::Delay(2),
+            child: Box::new(BehaviorNode::Action(1)),
+        };
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+
+        let mut statuses = Vec::new();
+        for _ in 0..4 {
+            statuses.push(tick_once(
                 &node,
                 &mut states,
                 &mut bb,
                 None,
                 &mut actions,
-                &conditions
-            ),
-            Status::Running
-        );
+                &conditions,
+            ));
+        }
         assert_eq!(
-            tick_once(
-                &node,
-                &mut states,
-                &mut bb,
-                None,
-                &mut actions,
-                &conditions
-            ),
-            Status::Failure
+            statuses,
+            vec![
+                Status::Running,
+                Status::Running,
+                Status::Success,
+                Status::Running
+            ]
         );
+        assert_eq!(actions.calls, vec![1]);
     }
 
     #[test]
-    fn tick_decorator_timeout() {
+    fn tick_repeat_reports_iterations_to_observer() {
         let node = BehaviorNode::Decorator {
-            decorator: Decorator::Timeout(2),
+            decorator: Decorator::Repeat(3),
             child: Box::new(BehaviorNode::Action(1)),
         };
-        let mut script = BTreeMap::new();
-        script.insert(1, vec![Status::Running, Status::Running, Status::Running]);
-        let mut actions = ScriptedActionHandler::with_script(script);
-        let conditions = ScriptedConditionHandler::default();
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
+        let mut actions = ScriptedActionHandler::default();
+        let conditions = ScriptedConditionHandler::default();
+        let mut observer = RecordingObserver::default();
 
-        assert_eq!(
-            tick_once(
+        for tick in 0..3 {
+            let mut ctx = Context::new(tick, 1, &mut bb, None);
+            tick_node(
                 &node,
+                0,
                 &mut states,
-                &mut bb,
-                None,
+                &mut ctx,
                 &mut actions,
-                &conditions
-            ),
-            Status::Running
+                &conditions,
+                &mut observer,
+            );
+        }
+        let iterations: Vec<_> = observer
+            .events
+            .iter()
+            .filter(|event| matches!(event, ObserverEvent::Iteration(..)))
+            .cloned()
+            .collect();
+        assert_eq!(
+            iterations,
+            vec![
+                ObserverEvent::Iteration(0, 1),
+                ObserverEvent::Iteration(0, 2),
+                ObserverEvent::Iteration(0, 3),
+            ]
         );
         assert_eq!(
-            tick_once(
-                &node,
-                &mut states,
-                &mut bb,
-                None,
-                &mut actions,
-                &conditions
-            ),
-            Status::Failure
+            observer.events.last(),
+            Some(&ObserverEvent::Exit(0, Status::Success))
+        );
+    }
+
+    #[test]
+    fn tick_reactive_selector_reports_cancelled_branch() {
+        let node = BehaviorNode::ReactiveSelector(vec![
+            BehaviorNode::Condition(1),
+            BehaviorNode::Sequence(vec![BehaviorNode::Action(2)]),
+        ]);
+        let mut script = BTreeMap::new();
+        script.insert(2, vec![Status::Running]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let mut observer = RecordingObserver::default();
+
+        let blocked = ScriptedConditionHandler::default();
+        let mut ctx = Context::new(1, 1, &mut bb, None);
+        let status = tick_node(
+            &node,
+            0,
+            &mut states,
+            &mut ctx,
+            &mut actions,
+            &blocked,
+            &mut observer,
+        );
+        assert_eq!(status, Status::Running);
+        assert!(!observer.events.contains(&ObserverEvent::Cancel(3)));
+
+        let mut open = ScriptedConditionHandler::default();
+        open.values.insert(1, true);
+        let mut ctx = Context::new(2, 1, &mut bb, None);
+        let status = tick_node(
+            &node,
+            0,
+            &mut states,
+            &mut ctx,
+            &mut actions,
+            &open,
+            &mut observer,
+        );
+        assert_eq!(status, Status::Success);
+        let cancelled: Vec<_> = observer
+            .events
+            .iter()
+            .filter(|event| matches!(event, ObserverEvent::Cancel(_)))
+            .cloned()
+            .collect();
+        assert_eq!(
+            cancelled,
+            vec![ObserverEvent::Cancel(2), ObserverEvent::Cancel(3)]
         );
+        assert_eq!(actions.aborted, vec![2]);
     }
 
     #[test]
-    fn tick_decorator_force_success() {
-        let node = BehaviorNode::Decorator {
-            decorator: Decorator::ForceSuccess,
-            child: Box::new(BehaviorNode::Action(1)),
+    #[cfg(target_pointer_width = "64")]
+    fn node_state_size_regression() {
+        // Child indices are `u16` and rarely used fields live in `NodeExtra`.
+        assert_eq!(core::mem::size_of::<NodeState>(), 40);
+    }
+
+    #[test]
+    fn tick_random_selector_seed_decorrelates_nodes() {
+        let random = |seed| BehaviorNode::RandomSelector {
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            seed,
         };
-        let mut script = BTreeMap::new();
-        script.insert(1, vec![Status::Failure]);
-        let mut actions = ScriptedActionHandler::with_script(script);
         let conditions = ScriptedConditionHandler::default();
-        let mut states = states_for(&node);
         let mut bb = Blackboard::new();
-        assert_eq!(
+        let mut pick = |seed| {
+            let node = random(seed);
+            let mut states = states_for(&node);
+            let mut actions = ScriptedActionHandler::default();
+            let mut rng = SeqRng::new(vec![4]);
             tick_once(
                 &node,
                 &mut states,
                 &mut bb,
-                None,
+                Some(&mut rng),
                 &mut actions,
-                &conditions
-            ),
-            Status::Success
-        );
+                &conditions,
+            );
+            actions.calls[0]
+        };
+
+        assert_eq!(pick(None), 1);
+        assert_eq!(pick(None), pick(None));
+        assert_eq!(pick(Some(1)), 1);
+        assert_eq!(pick(Some(3)), 2);
     }
 
     #[test]
-    fn tick_decorator_force_failure() {
-        let node = BehaviorNode::Decorator {
-            decorator: Decorator::ForceFailure,
-            child: Box::new(BehaviorNode::Action(1)),
+    fn tick_weighted_selector_seed_decorrelates_nodes() {
+        let weighted = |seed| BehaviorNode::WeightedSelector {
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            weights: vec![1, 1],
+            seed,
         };
-        let mut actions = ScriptedActionHandler::default();
         let conditions = ScriptedConditionHandler::default();
-        let mut states = states_for(&node);
         let mut bb = Blackboard::new();
-        assert_eq!(
+        let mut pick = |seed| {
+            let node = weighted(seed);
+            let mut states = states_for(&node);
+            let mut actions = ScriptedActionHandler::default();
+            let mut rng = SeqRng::new(vec![4]);
             tick_once(
                 &node,
                 &mut states,
                 &mut bb,
-                None,
+                Some(&mut rng),
                 &mut actions,
-                &conditions
-            ),
-            Status::Failure
-        );
+                &conditions,
+            );
+            actions.calls[0]
+        };
+
+        assert_eq!(pick(None), 1);
+        assert_ne!(pick(Some(1)), pick(Some(3)));
     }
 
     #[test]
-    fn tick_wait_counts_ticks() {
-        let node = BehaviorNode::Wait(3);
+    fn tick_decorator_once_runs_child_once() {
+        let node = BehaviorNode::Sequence(vec![
+            BehaviorNode::Decorator {
+                decorator: Decorator::Once,
+                child: Box::new(BehaviorNode::Action(1)),
+            },
+            BehaviorNode::Action(2),
+        ]);
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Running, Status::Success]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
+
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Running);
+        for _ in 0..3 {
+            let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+            assert_eq!(status, Status::Success);
+        }
+        assert_eq!(actions.calls, vec![1, 1, 2, 2, 2]);
+
+        states[1].reset();
+        tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(actions.calls, vec![1, 1, 2, 2, 2, 2]);
+
+        states[1] = NodeState::default();
+        tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(actions.calls, vec![1, 1, 2, 2, 2, 2, 1, 2]);
+    }
+
+    #[test]
+    fn tick_decorator_once_survives_looping_parent() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::Repeat(3),
+            child: Box::new(BehaviorNode::Sequence(vec![
+                BehaviorNode::Decorator {
+                    decorator: Decorator::Once,
+                    child: Box::new(BehaviorNode::Action(7)),
+                },
+                BehaviorNode::Action(8),
+            ])),
+        };
         let mut actions = ScriptedActionHandler::default();
         let conditions = ScriptedConditionHandler::default();
-        assert_eq!(
-            tick_once(
-                &node,
-                &mut states,
-                &mut bb,
-                None,
-                &mut actions,
-                &conditions
-            ),
-            Status::Running
-        );
-        assert_eq!(
-            tick_once(
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+
+        for _ in 0..3 {
+            tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        }
+        assert_eq!(actions.calls, vec![7, 8, 8, 8]);
+    }
+
+    #[test]
+    fn tick_timed_selector_falls_back_after_timeout() {
+        let node = BehaviorNode::TimedSelector {
+            max_ticks: 3,
+            primary: Box::new(BehaviorNode::Action(1)),
+            fallback: Box::new(BehaviorNode::Action(2)),
+        };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Running; 4]);
+        script.insert(2, vec![Status::Running, Status::Success]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+
+        let mut statuses = Vec::new();
+        for _ in 0..4 {
+            statuses.push(tick_once(
                 &node,
                 &mut states,
                 &mut bb,
                 None,
                 &mut actions,
-                &conditions
-            ),
-            Status::Running
-        );
+                &conditions,
+            ));
+        }
         assert_eq!(
-            tick_once(
-                &node,
-                &mut states,
-                &mut bb,
-                None,
-                &mut actions,
-                &conditions
-            ),
-            Status::Success
+            statuses,
+            vec![
+                Status::Running,
+                Status::Running,
+                Status::Running,
+                Status::Success
+            ]
         );
+        assert_eq!(actions.calls, vec![1, 1, 2, 2]);
+        assert_eq!(actions.aborted, vec![1]);
+        assert_eq!(states[0].selected_index(), None);
+        assert_eq!(states[0].tick_counter, 0);
     }
 
     #[test]
-    fn tick_action_delegates() {
-        let node = BehaviorNode::Action(5);
-        let mut states = states_for(&node);
-        let mut bb = Blackboard::new();
+    fn tick_compare_operators() {
         let mut actions = ScriptedActionHandler::default();
         let conditions = ScriptedConditionHandler::default();
-        let status = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
-        );
-        assert_eq!(status, Status::Success);
-        assert_eq!(actions.calls, vec![5]);
-    }
-
-    #[test]
-    fn tick_condition_true() {
-        let node = BehaviorNode::Condition(10);
-        let mut states = states_for(&node);
         let mut bb = Blackboard::new();
-        let mut actions = ScriptedActionHandler::default();
-        let mut conditions = ScriptedConditionHandler::default();
-        conditions.values.insert(10, true);
-        let status = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
-        );
-        assert_eq!(status, Status::Success);
+        bb.set_int(1, 5);
+        bb.set_float(2, 2.5);
+
+        let cases = [
+            (1, CompareOp::Eq, 5, Status::Success),
+            (1, CompareOp::Eq, 4, Status::Failure),
+            (1, CompareOp::Ne, 4, Status::Success),
+            (1, CompareOp::Ne, 5, Status::Failure),
+            (1, CompareOp::Lt, 6, Status::Success),
+            (1, CompareOp::Lt, 5, Status::Failure),
+            (1, CompareOp::Le, 5, Status::Success),
+            (1, CompareOp::Le, 4, Status::Failure),
+            (1, CompareOp::Gt, 4, Status::Success),
+            (1, CompareOp::Gt, 5, Status::Failure),
+            (1, CompareOp::Ge, 5, Status::Success),
+            (1, CompareOp::Ge, 6, Status::Failure),
+            (2, CompareOp::Gt, 2, Status::Success),
+            (2, CompareOp::Lt, 3, Status::Success),
+            (2, CompareOp::Eq, 2, Status::Failure),
+            (9, CompareOp::Ne, 0, Status::Failure),
+            (9, CompareOp::Eq, 0, Status::Failure),
+        ];
+        for (key, op, value, expected) in cases {
+            let node = BehaviorNode::Compare { key, op, value };
+            let mut states = states_for(&node);
+            let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+            assert_eq!(status, expected, "{key} {op:?} {value}");
+        }
     }
 
     #[test]
-    fn tick_condition_false() {
-        let node = BehaviorNode::Condition(10);
-        let mut states = states_for(&node);
-        let mut bb = Blackboard::new();
-        let mut actions = ScriptedActionHandler::default();
-        let mut conditions = ScriptedConditionHandler::default();
-        conditions.values.insert(10, false);
-        let status = tick_once(
-            &node,
-            &mut states,
-            &mut bb,
-            None,
-            &mut actions,
-            &conditions,
-        );
-        assert_eq!(status, Status::Failure);
+    fn tick_decorator_remap() {
+        let swap = Decorator::Remap {
+            on_success: Status::Failure,
+            on_failure: Status::Success,
+        };
+        let fail_to_success = Decorator::Remap {
+            on_success: Status::Success,
+            on_failure: Status::Success,
+        };
+        let cases = [
+            (swap.clone(), Status::Success, Status::Failure),
+            (swap.clone(), Status::Failure, Status::Success),
+            (swap, Status::Running, Status::Running),
+            (fail_to_success.clone(), Status::Failure, Status::Success),
+            (fail_to_success, Status::Success, Status::Success),
+        ];
+        for (decorator, child, expected) in cases {
+            let node = BehaviorNode::Decorator {
+                decorator,
+                child: Box::new(BehaviorNode::Action(1)),
+            };
+            let mut script = BTreeMap::new();
+            script.insert(1, vec![child]);
+            let mut actions = ScriptedActionHandler::with_script(script);
+            let conditions = ScriptedConditionHandler::default();
+            let mut states = states_for(&node);
+            let mut bb = Blackboard::new();
+            let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+            assert_eq!(status, expected, "{child:?}");
+        }
     }
 
     #[test]
-    fn tick_random_selector_persists_running() {
-        let node =
-            BehaviorNode::RandomSelector(vec![BehaviorNode::Action(1), BehaviorNode::Action(2)]);
+    fn tick_decorator_running_timeout_fails_stuck_sequence() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::RunningTimeout(2),
+            child: Box::new(BehaviorNode::Sequence(vec![
+                BehaviorNode::Action(1),
+                BehaviorNode::Action(2),
+            ])),
+        };
         let mut script = BTreeMap::new();
         script.insert(1, vec![Status::Running, Status::Success]);
-        script.insert(2, vec![Status::Success]);
+        script.insert(2, vec![Status::Running; 4]);
         let mut actions = ScriptedActionHandler::with_script(script);
         let conditions = ScriptedConditionHandler::default();
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
-        let mut rng = SeqRng::new(vec![0, 1]);
 
-        assert_eq!(
-            tick_once(
+        let mut statuses = Vec::new();
+        for _ in 0..4 {
+            statuses.push(tick_once(
                 &node,
                 &mut states,
                 &mut bb,
-                Some(&mut rng),
+                None,
                 &mut actions,
-                &conditions
-            ),
-            Status::Running
-        );
+                &conditions,
+            ));
+        }
         assert_eq!(
-            tick_once(
-                &node,
-                &mut states,
-                &mut bb,
-                Some(&mut rng),
-                &mut actions,
-                &conditions
-            ),
-            Status::Success
+            statuses,
+            vec![
+                Status::Running,
+                Status::Running,
+                Status::Running,
+                Status::Failure
+            ]
         );
-        assert_eq!(actions.calls, vec![1, 1]);
+        assert_eq!(actions.calls, vec![1, 1, 2, 2, 2]);
+        assert_eq!(actions.aborted, vec![2]);
+        assert_eq!(states[1].running_child, 0);
     }
 
     #[test]
-    fn tick_weighted_selector_respects_weights() {
-        let node = BehaviorNode::WeightedSelector {
-            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
-            weights: vec![1, 9],
+    fn tick_interrupt_parallel_resets_siblings() {
+        let node = BehaviorNode::InterruptParallel {
+            policy: ParallelPolicy::RequireAll,
+            children: vec![
+                BehaviorNode::Condition(1),
+                BehaviorNode::Sequence(vec![BehaviorNode::Wait(3), BehaviorNode::Action(2)]),
+            ],
+            interrupts: vec![true, false],
         };
+        let mut actions = ScriptedActionHandler::default();
+        let mut conditions = ScriptedConditionHandler::default();
         let mut states = states_for(&node);
         let mut bb = Blackboard::new();
-        let mut actions = ScriptedActionHandler::default();
-        let conditions = ScriptedConditionHandler::default();
-        let mut rng = SeqRng::new(vec![0, 9]);
 
-        assert_eq!(
-            tick_once(
+        for _ in 0..2 {
+            let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+            assert_eq!(status, Status::Running);
+        }
+        assert_eq!(states[3].tick_counter, 2);
+
+        conditions.values.insert(1, true);
+        let status = tick_once(&node, &mut states, &mut bb, None, &mut actions, &conditions);
+        assert_eq!(status, Status::Running);
+        assert_eq!(states[3].tick_counter, 0);
+
+        conditions.values.insert(1, false);
+        let mut statuses = Vec::new();
+        for _ in 0..3 {
+            statuses.push(tick_once(
                 &node,
                 &mut states,
                 &mut bb,
-                Some(&mut rng),
+                None,
                 &mut actions,
-                &conditions
-            ),
-            Status::Success
-        );
+                &conditions,
+            ));
+        }
         assert_eq!(
+            statuses,
+            vec![Status::Running, Status::Running, Status::Success]
+        );
+        assert_eq!(actions.calls, vec![2]);
+    }
+
+    #[test]
+    fn tick_shuffled_selector_seed_changes_order() {
+        let shuffled = |seed| BehaviorNode::ShuffledSelector {
+            children: vec![
+                BehaviorNode::Action(1),
+                BehaviorNode::Action(2),
+                BehaviorNode::Action(3),
+            ],
+            seed,
+        };
+        let conditions = ScriptedConditionHandler::default();
+        let mut bb = Blackboard::new();
+        let mut order = |seed| {
+            let node = shuffled(seed);
+            let mut states = states_for(&node);
+            let script = (1..=3).map(|id| (id, vec![Status::Running])).collect();
+            let mut actions = ScriptedActionHandler::with_script(script);
+            let mut rng = SeqRng::new(vec![0, 0]);
             tick_once(
                 &node,
                 &mut states,
                 &mut bb,
                 Some(&mut rng),
                 &mut actions,
-                &conditions
-            ),
-            Status::Success
+                &conditions,
+            );
+            states[0].extra().unwrap().shuffle_order.clone()
+        };
+
+        assert_eq!(order(None), vec![1, 2, 0]);
+        assert_eq!(order(Some(5)), order(Some(5)));
+        assert_ne!(order(Some(5)), order(None));
+    }
+
+    #[test]
+    fn tick_on_abort_skips_actions_never_executed() {
+        let node = BehaviorNode::Decorator {
+            decorator: Decorator::InterruptIf(7),
+            child: Box::new(BehaviorNode::Parallel {
+                policy: ParallelPolicy::RequireAll,
+                children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+                short_circuit: false,
+            }),
+        };
+        let mut script = BTreeMap::new();
+        script.insert(1, vec![Status::Running]);
+        let mut actions = ScriptedActionHandler::with_script(script);
+        let conditions = ScriptedConditionHandler::default();
+        let mut states = states_for(&node);
+        let mut bb = Blackboard::new();
+        let mut observer = NoOpObserver;
+
+        let mut ctx = Context::new(1, 1, &mut bb, None).with_leaf_budget(Some(1));
+        let status = tick_node(
+            &node,
+            0,
+            &mut states,
+            &mut ctx,
+            &mut actions,
+            &conditions,
+            &mut observer,
         );
-        assert_eq!(actions.calls, vec![1, 2]);
+        assert_eq!(status, Status::Running);
+        assert_eq!(actions.calls, vec![1]);
+
+        bb.set_bool(7, true);
+        let mut ctx = Context::new(2, 1, &mut bb, None);
+        let status = tick_node(
+            &node,
+            0,
+            &mut states,
+            &mut ctx,
+            &mut actions,
+            &conditions,
+            &mut observer,
+        );
+        assert_eq!(status, Status::Failure);
+        assert_eq!(actions.calls, vec![1]);
+        assert_eq!(actions.aborted, vec![1]);
     }
 }
@@ -1,40 +1,141 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
 use alloc::vec;
 use alloc::vec::Vec;
 
+use core::any::Any;
+use core::marker::PhantomData;
+
 use rand_core::RngCore;
 
-use crate::tick::{assign_ids, tick_node, NodeState};
+use crate::builder::has_empty_composite;
+use crate::float::Float;
+use crate::subtree::SubtreeLibrary;
+use crate::tick::{
+    assign_ids, node_children, subtree_size, tick_root, NodeState, RootTick, TickScratch,
+    TickSettings, MAX_CHILDREN,
+};
 use crate::{
-    ActionHandler, BehaviorNode, Blackboard, ConditionHandler, Context, Observer, Status,
+    ActionHandler, BehaviorNode, Blackboard, BlackboardStore, BlackboardValue, ConditionHandler,
+    Observer, ObserverEvent, ParallelPolicy, RecordingObserver, Status, TickOutcome, TreeConfig,
+    TreeError,
 };
 
-pub struct BehaviorTree<A, C> {
+/// Snapshot of a tree's runtime state, produced by [`BehaviorTree::export_state`].
+#[derive(Clone, Debug)]
+pub struct TreeState<B = Blackboard> {
+    pub states: Vec<NodeState>,
+    pub tick_count: u64,
+    pub blackboard: B,
+}
+
+/// A behavior tree plus its runtime state; `F` is the float type used for utility scoring and
+/// `B` the [`BlackboardStore`] its nodes and handlers read and write.
+pub struct BehaviorTree<A, C, F = f32, B = Blackboard> {
     root: BehaviorNode<A, C>,
     states: Vec<NodeState>,
-    blackboard: Blackboard,
+    blackboard: B,
     tick_count: u64,
+    settings: TickSettings,
+    paused: bool,
+    labels: BTreeMap<usize, &'static str>,
+    rng: Option<Box<dyn RngCore + Send>>,
+    scratch: TickScratch,
+    library: SubtreeLibrary<A, C>,
+    _float: PhantomData<F>,
 }
 
 impl<A, C> BehaviorTree<A, C> {
     pub fn new(root: BehaviorNode<A, C>) -> Self {
+        Self::with_float(root)
+    }
+
+    /// Like [`new`](Self::new), but validates `root` against the default [`TreeConfig`].
+    pub fn try_new(root: BehaviorNode<A, C>) -> Result<Self, TreeError> {
+        Self::try_with_config(root, &TreeConfig::default())
+    }
+
+    /// Like [`try_new`](Self::try_new), with the depth limit and empty-composite policy
+    /// taken from `config`.
+    pub fn try_with_config(
+        root: BehaviorNode<A, C>,
+        config: &TreeConfig,
+    ) -> Result<Self, TreeError> {
+        Self::try_with_float_and_blackboard(root, Blackboard::new(), config)
+    }
+
+    /// Creates a tree that owns `rng` and uses it whenever no RNG is passed to a tick.
+    pub fn with_rng<R: RngCore + Send + 'static>(root: BehaviorNode<A, C>, rng: R) -> Self {
+        let mut tree = Self::new(root);
+        tree.set_rng(rng);
+        tree
+    }
+}
+
+impl<A, C, B: BlackboardStore> BehaviorTree<A, C, f32, B> {
+    /// Creates a tree backed by `blackboard`, e.g. an allocation-free
+    /// [`ArrayBlackboard`](crate::ArrayBlackboard), instead of a [`Blackboard`].
+    pub fn with_blackboard(root: BehaviorNode<A, C>, blackboard: B) -> Self {
+        Self::with_float_and_blackboard(root, blackboard)
+    }
+}
+
+impl<A, C, F: Float, B: BlackboardStore + Default> BehaviorTree<A, C, F, B> {
+    /// Creates a tree that scores utility selectors in `F` instead of `f32`, starting from an
+    /// empty `B`.
+    pub fn with_float(root: BehaviorNode<A, C>) -> Self {
+        Self::with_float_and_blackboard(root, B::default())
+    }
+}
+
+impl<A, C, F: Float, B: BlackboardStore> BehaviorTree<A, C, F, B> {
+    /// The general constructor behind [`new`](BehaviorTree::new),
+    /// [`with_float`](Self::with_float) and [`with_blackboard`](BehaviorTree::with_blackboard);
+    /// pair it with [`set_rng`](Self::set_rng) to own an RNG.
+    pub fn with_float_and_blackboard(root: BehaviorNode<A, C>, blackboard: B) -> Self {
         let node_count = assign_ids(&root).max(1);
         Self {
             root,
             states: vec![NodeState::default(); node_count],
-            blackboard: Blackboard::new(),
+            blackboard,
             tick_count: 0,
+            settings: TickSettings::default(),
+            paused: false,
+            labels: BTreeMap::new(),
+            rng: None,
+            scratch: TickScratch::new(),
+            library: SubtreeLibrary::new(),
+            _float: PhantomData,
         }
     }
 
+    /// Like [`with_float_and_blackboard`](Self::with_float_and_blackboard), but validates
+    /// `root` against `config` first and keeps `config` for ticking.
+    pub fn try_with_float_and_blackboard(
+        root: BehaviorNode<A, C>,
+        blackboard: B,
+        config: &TreeConfig,
+    ) -> Result<Self, TreeError> {
+        validate_tree_with(&root, config)?;
+        let mut tree = Self::with_float_and_blackboard(root, blackboard);
+        tree.set_config(config.clone());
+        Ok(tree)
+    }
+
+    /// Stores an owned RNG used by ticks that don't pass a borrowed one.
+    pub fn set_rng<R: RngCore + Send + 'static>(&mut self, rng: R) {
+        self.rng = Some(Box::new(rng));
+    }
+
     pub fn tick<AH, CH, O>(
         &mut self,
         action_handler: &mut AH,
         condition_handler: &CH,
         observer: &mut O,
-    ) -> Status
+    ) -> TickOutcome
     where
-        AH: ActionHandler<A>,
-        CH: ConditionHandler<C>,
+        AH: ActionHandler<A, B>,
+        CH: ConditionHandler<C, B>,
         O: Observer,
     {
         self.tick_with(1, None, action_handler, condition_handler, observer)
@@ -47,36 +148,175 @@ impl<A, C> BehaviorTree<A, C> {
         action_handler: &mut AH,
         condition_handler: &CH,
         observer: &mut O,
-    ) -> Status
+    ) -> TickOutcome
+    where
+        AH: ActionHandler<A, B>,
+        CH: ConditionHandler<C, B>,
+        O: Observer,
+    {
+        self.tick_with_user_data(
+            delta_ticks,
+            rng,
+            None,
+            action_handler,
+            condition_handler,
+            observer,
+        )
+    }
+
+    /// Like [`tick_with`](Self::tick_with), exposing `user_data` to handlers via
+    /// [`Context::user_data`](crate::Context::user_data).
+    pub fn tick_with_user_data<'a, AH, CH, O>(
+        &'a mut self,
+        delta_ticks: u32,
+        rng: Option<&'a mut dyn RngCore>,
+        user_data: Option<&'a mut dyn Any>,
+        action_handler: &mut AH,
+        condition_handler: &CH,
+        observer: &mut O,
+    ) -> TickOutcome
     where
-        AH: ActionHandler<A>,
-        CH: ConditionHandler<C>,
+        AH: ActionHandler<A, B>,
+        CH: ConditionHandler<C, B>,
         O: Observer,
     {
+        if self.paused {
+            return TickOutcome::completed(self.states[0].last_status.unwrap_or(Status::Running));
+        }
         self.tick_count = self.tick_count.saturating_add(delta_ticks as u64);
-        let mut ctx = Context::new(self.tick_count, delta_ticks, &mut self.blackboard, rng);
-        tick_node(
+        let owned_rng = self.rng.as_deref_mut().map(|r| r as &mut dyn RngCore);
+        let run = RootTick {
+            states: &mut self.states,
+            blackboard: &mut self.blackboard,
+            scratch: &mut self.scratch,
+            tick: self.tick_count,
+            delta_ticks,
+            rng: rng.or(owned_rng),
+            user_data,
+        };
+        tick_root::<F, _, _, _, _, _, _>(
             &self.root,
-            0,
-            &mut self.states,
-            &mut ctx,
+            &self.library,
+            &self.settings,
+            run,
             action_handler,
             condition_handler,
             observer,
         )
     }
 
-    pub fn blackboard(&self) -> &Blackboard {
+    /// Ticks once with a fresh [`RecordingObserver`] and returns the events it collected.
+    pub fn tick_recorded<AH, CH>(
+        &mut self,
+        action_handler: &mut AH,
+        condition_handler: &CH,
+    ) -> (Status, Vec<ObserverEvent>)
+    where
+        AH: ActionHandler<A, B>,
+        CH: ConditionHandler<C, B>,
+    {
+        let mut observer = RecordingObserver::default();
+        let outcome = self.tick(action_handler, condition_handler, &mut observer);
+        (outcome.status, observer.events)
+    }
+
+    /// Ticks once and also returns, in pre-order, the ids of every node that exited the tick
+    /// Running, recorded as the tick descends rather than reconstructed afterwards.
+    pub fn tick_tracing<AH, CH, O>(
+        &mut self,
+        action_handler: &mut AH,
+        condition_handler: &CH,
+        observer: &mut O,
+    ) -> (Status, Vec<usize>)
+    where
+        AH: ActionHandler<A, B>,
+        CH: ConditionHandler<C, B>,
+        O: Observer,
+    {
+        let mut tracer = RunningPathObserver {
+            inner: observer,
+            path: Vec::new(),
+            open: Vec::new(),
+        };
+        let outcome = self.tick(action_handler, condition_handler, &mut tracer);
+        (outcome.status, tracer.path)
+    }
+
+    /// Ticks until the tree leaves `Running` or `max_iters` ticks have been spent.
+    pub fn tick_until_settled<AH, CH, O>(
+        &mut self,
+        max_iters: u32,
+        action_handler: &mut AH,
+        condition_handler: &CH,
+        observer: &mut O,
+    ) -> (Status, u32)
+    where
+        AH: ActionHandler<A, B>,
+        CH: ConditionHandler<C, B>,
+        O: Observer,
+    {
+        let mut status = Status::Running;
+        let mut iters = 0u32;
+        while iters < max_iters {
+            status = self
+                .tick(action_handler, condition_handler, observer)
+                .status;
+            iters += 1;
+            if status.is_done() {
+                break;
+            }
+        }
+        (status, iters)
+    }
+
+    pub fn blackboard(&self) -> &B {
         &self.blackboard
     }
 
-    pub fn blackboard_mut(&mut self) -> &mut Blackboard {
+    pub fn blackboard_mut(&mut self) -> &mut B {
         &mut self.blackboard
     }
 
+    /// Swaps in a new root, keeping node state when the shape is unchanged so in-flight
+    /// behaviors continue; otherwise states and labels are rebuilt for the new tree.
+    pub fn replace_root(&mut self, new_root: BehaviorNode<A, C>) {
+        if !self.root.same_shape(&new_root) {
+            self.states = vec![NodeState::default(); assign_ids(&new_root).max(1)];
+            self.labels.clear();
+        }
+        self.root = new_root;
+    }
+
+    /// Captures node states, the tick count and the blackboard, e.g. for save games.
+    pub fn export_state(&self) -> TreeState<B>
+    where
+        B: Clone,
+    {
+        TreeState {
+            states: self.states.clone(),
+            tick_count: self.tick_count,
+            blackboard: self.blackboard.clone(),
+        }
+    }
+
+    /// Restores a snapshot from [`export_state`](Self::export_state); fails if it was taken
+    /// from a tree with a different node count.
+    pub fn import_state(&mut self, state: TreeState<B>) -> Result<(), TreeError> {
+        if state.states.len() != self.states.len() {
+            return Err(TreeError::StateSizeMismatch {
+                expected: self.states.len(),
+                actual: state.states.len(),
+            });
+        }
+        self.states = state.states;
+        self.tick_count = state.tick_count;
+        self.blackboard = state.blackboard;
+        Ok(())
+    }
+
     pub fn reset(&mut self) {
         for state in &mut self.states {
-            state.reset();
+            *state = NodeState::default();
         }
         self.tick_count = 0;
     }
@@ -86,6 +326,127 @@ impl<A, C> BehaviorTree<A, C> {
         self.blackboard.clear();
     }
 
+    /// Sets the subtrees that `BehaviorNode::SubtreeRef` nodes resolve against.
+    pub fn set_library(&mut self, library: SubtreeLibrary<A, C>) {
+        self.library = library;
+    }
+
+    pub fn library(&self) -> &SubtreeLibrary<A, C> {
+        &self.library
+    }
+
+    /// Replaces the config consulted while ticking, e.g. for empty composite statuses.
+    pub fn set_config(&mut self, config: TreeConfig) {
+        self.settings.config = config;
+    }
+
+    pub fn config(&self) -> &TreeConfig {
+        &self.settings.config
+    }
+
+    /// Caps how many action/condition leaves run per tick; `None` removes the cap.
+    pub fn set_leaf_budget(&mut self, budget: Option<u32>) {
+        self.settings.leaf_budget = budget;
+    }
+
+    pub fn leaf_budget(&self) -> Option<u32> {
+        self.settings.leaf_budget
+    }
+
+    /// Freezes the tree: ticks return the root's last status (or `Running`) without running
+    /// any nodes or advancing the tick count.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Sets the millisecond delta reported to `WaitMillis` nodes on subsequent ticks.
+    pub fn set_delta_millis(&mut self, delta_millis: u32) {
+        self.settings.delta_millis = delta_millis;
+    }
+
+    pub fn delta_millis(&self) -> u32 {
+        self.settings.delta_millis
+    }
+
+    /// Attaches debug labels keyed by node id, as produced by
+    /// [`TreeBuilder::build_with_labels`](crate::TreeBuilder::build_with_labels).
+    pub fn set_labels(&mut self, labels: BTreeMap<usize, &'static str>) {
+        self.labels = labels;
+    }
+
+    pub fn label_for(&self, node_id: usize) -> Option<&'static str> {
+        self.labels.get(&node_id).copied()
+    }
+
+    /// Child indices leading from the root to `node_id`; empty for the root or an unknown id.
+    pub fn node_path(&self, node_id: usize) -> Vec<usize> {
+        let mut path = Vec::new();
+        let mut node = &self.root;
+        let mut current_id = 0usize;
+        while current_id != node_id {
+            let mut child_id = current_id + 1;
+            let mut next = None;
+            for (i, child) in node_children(node).enumerate() {
+                let size = subtree_size(child);
+                if node_id < child_id + size {
+                    next = Some((i, child));
+                    break;
+                }
+                child_id += size;
+            }
+            match next {
+                Some((i, child)) => {
+                    path.push(i);
+                    node = child;
+                    current_id = child_id;
+                }
+                _ => return Vec::new(),
+            }
+        }
+        path
+    }
+
+    /// Ids of the deepest nodes on every branch that ended the last tick Running, found by
+    /// following `NodeState::last_status` down from the root through nodes entered that tick.
+    pub fn active_leaves(&self) -> Vec<usize> {
+        let mut leaves = Vec::new();
+        if self.ran_last_tick(0) {
+            self.collect_active(&self.root, 0, &mut leaves);
+        }
+        leaves
+    }
+
+    fn collect_active(&self, node: &BehaviorNode<A, C>, node_id: usize, leaves: &mut Vec<usize>) {
+        let mut child_id = node_id + 1;
+        let mut descended = false;
+        for child in node_children(node) {
+            if self.ran_last_tick(child_id) {
+                self.collect_active(child, child_id, leaves);
+                descended = true;
+            }
+            child_id += subtree_size(child);
+        }
+        if !descended {
+            leaves.push(node_id);
+        }
+    }
+
+    /// Whether `node_id` was entered on the latest tick and left Running; a stale Running status
+    /// from an earlier tick doesn't count.
+    fn ran_last_tick(&self, node_id: usize) -> bool {
+        let state = &self.states[node_id];
+        state.last_status == Some(Status::Running) && state.last_enter_tick == self.tick_count
+    }
+
+    /// Runtime state of `node_id`, including profiling counters. Panics if the id is out of range.
+    pub fn node_state(&self, node_id: usize) -> &NodeState {
+        &self.states[node_id]
+    }
+
     pub fn tick_count(&self) -> u64 {
         self.tick_count
     }
@@ -99,13 +460,172 @@ impl<A, C> BehaviorTree<A, C> {
     }
 }
 
+/// Forwards to `inner` while keeping only the nodes that exit Running in `path`.
+struct RunningPathObserver<'a, O> {
+    inner: &'a mut O,
+    path: Vec<usize>,
+    /// Position in `path` of each node currently being ticked.
+    open: Vec<usize>,
+}
+
+impl<O: Observer> Observer for RunningPathObserver<'_, O> {
+    fn on_enter(&mut self, node_id: usize) {
+        self.open.push(self.path.len());
+        self.path.push(node_id);
+        self.inner.on_enter(node_id);
+    }
+
+    fn on_exit(&mut self, node_id: usize, status: Status) {
+        if let Some(position) = self.open.pop() {
+            if status != Status::Running {
+                self.path.truncate(position);
+            }
+        }
+        self.inner.on_exit(node_id, status);
+    }
+
+    fn on_blackboard_write(&mut self, key: u32, value: BlackboardValue) {
+        self.inner.on_blackboard_write(key, value);
+    }
+
+    fn on_utility_score(&mut self, action_index: usize, score: f32) {
+        self.inner.on_utility_score(action_index, score);
+    }
+
+    fn on_iteration(&mut self, node_id: usize, iteration: u32) {
+        self.inner.on_iteration(node_id, iteration);
+    }
+
+    fn on_cancel(&mut self, node_id: usize) {
+        self.inner.on_cancel(node_id);
+    }
+}
+
+/// Checks that every weighted selector, weighted parallel and utility selector has one weight
+/// or id per child, that no `RequireN` parallel asks for more children than it has, and that
+/// no composite exceeds [`MAX_CHILDREN`].
+pub fn validate_tree<A, C>(node: &BehaviorNode<A, C>) -> Result<(), TreeError> {
+    let child_count = node_children(node).len();
+    if child_count > MAX_CHILDREN {
+        return Err(TreeError::TooManyChildren(child_count));
+    }
+    match node {
+        BehaviorNode::UtilitySelector {
+            children,
+            utility_ids,
+        } if children.len() != utility_ids.len() => {
+            return Err(TreeError::UtilityIdCountMismatch {
+                children: children.len(),
+                ids: utility_ids.len(),
+            });
+        }
+        BehaviorNode::WeightedSelector {
+            children, weights, ..
+        } if children.len() != weights.len() => {
+            return Err(TreeError::WeightCountMismatch {
+                children: children.len(),
+                weights: weights.len(),
+            });
+        }
+        BehaviorNode::BlackboardWeightedSelector {
+            children,
+            weight_keys,
+            ..
+        } if children.len() != weight_keys.len() => {
+            return Err(TreeError::WeightCountMismatch {
+                children: children.len(),
+                weights: weight_keys.len(),
+            });
+        }
+        BehaviorNode::Parallel {
+            policy: ParallelPolicy::RequireWeight { weights, .. },
+            children,
+            ..
+        } if children.len() != weights.len() => {
+            return Err(TreeError::WeightCountMismatch {
+                children: children.len(),
+                weights: weights.len(),
+            });
+        }
+        BehaviorNode::Parallel {
+            policy: ParallelPolicy::RequireN(required),
+            children,
+            ..
+        } if *required > children.len() => {
+            return Err(TreeError::ParallelThresholdTooHigh {
+                required: *required,
+                children: children.len(),
+            });
+        }
+        BehaviorNode::InterruptParallel {
+            children,
+            interrupts,
+            ..
+        } if children.len() != interrupts.len() => {
+            return Err(TreeError::InterruptCountMismatch {
+                children: children.len(),
+                interrupts: interrupts.len(),
+            });
+        }
+        BehaviorNode::InterruptParallel {
+            policy: ParallelPolicy::RequireN(required),
+            interrupts,
+            ..
+        } if *required > interrupts.iter().filter(|i| !**i).count() => {
+            return Err(TreeError::ParallelThresholdTooHigh {
+                required: *required,
+                children: interrupts.iter().filter(|i| !**i).count(),
+            });
+        }
+        BehaviorNode::InterruptParallel {
+            policy: ParallelPolicy::RequireWeight { weights, .. },
+            interrupts,
+            ..
+        } if interrupts.iter().filter(|i| !**i).count() != weights.len() => {
+            return Err(TreeError::WeightCountMismatch {
+                children: interrupts.iter().filter(|i| !**i).count(),
+                weights: weights.len(),
+            });
+        }
+        _ => {}
+    }
+    node_children(node).try_for_each(validate_tree)
+}
+
+/// Runs [`validate_tree`], then enforces `config.max_depth` and, unless
+/// `config.allow_empty_composites` is set, rejects composites without children.
+pub fn validate_tree_with<A, C>(
+    node: &BehaviorNode<A, C>,
+    config: &TreeConfig,
+) -> Result<(), TreeError> {
+    validate_tree(node)?;
+    let depth = tree_depth(node);
+    if depth > config.max_depth {
+        return Err(TreeError::MaxDepthExceeded(depth));
+    }
+    if !config.allow_empty_composites && has_empty_composite(node) {
+        return Err(TreeError::EmptyComposite);
+    }
+    Ok(())
+}
+
+/// Number of nodes on the longest root-to-leaf path.
+fn tree_depth<A, C>(node: &BehaviorNode<A, C>) -> usize {
+    1 + node_children(node).map(tree_depth).max().unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
     use crate::{
-        ActionHandler, BehaviorNode, ConditionHandler, Context, NoOpObserver, Status, TreeBuilder,
+        ActionHandler, BehaviorNode, ConditionHandler, Context, Decorator, NoOpObserver,
+        ObserverEvent, ParallelPolicy, RecordingObserver, SeededRng, Status, SubtreeLibrary,
+        TreeBuilder, TreeConfig, TreeError, MAX_SUBTREE_DEPTH,
     };
 
-    use super::BehaviorTree;
+    use super::{validate_tree, validate_tree_with, BehaviorTree, MAX_CHILDREN};
 
     struct UnitActions;
 
@@ -115,6 +635,18 @@ mod tests {
         }
     }
 
+    #[derive(Default)]
+    struct RecordingActions {
+        calls: Vec<u32>,
+    }
+
+    impl ActionHandler<u32> for RecordingActions {
+        fn execute(&mut self, action: &u32, _ctx: &mut Context) -> Status {
+            self.calls.push(*action);
+            Status::Success
+        }
+    }
+
     struct UnitConditions;
 
     impl ConditionHandler<u32> for UnitConditions {
@@ -162,6 +694,367 @@ mod tests {
         assert!(!tree.blackboard().has(1));
     }
 
+    #[test]
+    fn tree_leaf_budget_spreads_wide_sequence() {
+        let root: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .sequence()
+            .action(1u32)
+            .action(2u32)
+            .action(3u32)
+            .action(4u32)
+            .action(5u32)
+            .end()
+            .build();
+        let mut tree = BehaviorTree::new(root);
+        tree.set_leaf_budget(Some(2));
+        let mut actions = UnitActions;
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+
+        assert_eq!(
+            tree.tick(&mut actions, &conditions, &mut observer),
+            Status::Running
+        );
+        assert_eq!(tree.states[0].running_child, 2);
+        assert_eq!(
+            tree.tick(&mut actions, &conditions, &mut observer),
+            Status::Running
+        );
+        assert_eq!(tree.states[0].running_child, 4);
+        assert_eq!(
+            tree.tick(&mut actions, &conditions, &mut observer),
+            Status::Success
+        );
+    }
+
+    struct AbortingActions {
+        calls: Vec<u32>,
+    }
+
+    impl ActionHandler<u32> for AbortingActions {
+        fn execute(&mut self, action: &u32, ctx: &mut Context) -> Status {
+            self.calls.push(*action);
+            if *action == 2 {
+                ctx.request_abort();
+            }
+            Status::Success
+        }
+    }
+
+    #[test]
+    fn tree_abort_skips_remaining_siblings() {
+        let root: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .sequence()
+            .action(1u32)
+            .selector()
+            .sequence()
+            .action(2u32)
+            .action(3u32)
+            .end()
+            .action(4u32)
+            .end()
+            .action(5u32)
+            .end()
+            .build();
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = AbortingActions { calls: Vec::new() };
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+
+        let outcome = tree.tick(&mut actions, &conditions, &mut observer);
+        assert!(outcome.aborted);
+        assert_eq!(outcome.status, Status::Failure);
+        assert_eq!(actions.calls, vec![1, 2]);
+        assert!(tree.states.iter().all(|s| s.running_child == 0));
+
+        let outcome = tree.tick(&mut actions, &conditions, &mut observer);
+        assert!(outcome.aborted);
+        assert_eq!(actions.calls, vec![1, 2, 1, 2]);
+    }
+
+    #[derive(Default)]
+    struct AbortOnSecondTick {
+        aborted: Vec<u32>,
+    }
+
+    impl ActionHandler<u32> for AbortOnSecondTick {
+        fn execute(&mut self, action: &u32, ctx: &mut Context) -> Status {
+            if *action == 2 && ctx.tick() >= 2 {
+                ctx.request_abort();
+            }
+            Status::Running
+        }
+
+        fn on_abort(&mut self, action: &u32, _ctx: &mut Context) {
+            self.aborted.push(*action);
+        }
+    }
+
+    #[test]
+    fn tree_abort_notifies_running_actions() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::Parallel {
+            policy: ParallelPolicy::RequireAll,
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            short_circuit: false,
+        };
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = AbortOnSecondTick::default();
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+
+        assert!(!tree.tick(&mut actions, &conditions, &mut observer).aborted);
+        assert!(actions.aborted.is_empty());
+
+        assert!(tree.tick(&mut actions, &conditions, &mut observer).aborted);
+        assert_eq!(actions.aborted, vec![1, 2]);
+        assert!(tree.active_leaves().is_empty());
+    }
+
+    #[test]
+    fn tree_tick_until_settled_wait() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::Wait(3);
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = UnitActions;
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+        assert_eq!(
+            tree.tick_until_settled(10, &mut actions, &conditions, &mut observer),
+            (Status::Success, 3)
+        );
+    }
+
+    #[test]
+    fn tree_tick_until_settled_hits_cap() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::Wait(5);
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = UnitActions;
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+        assert_eq!(
+            tree.tick_until_settled(2, &mut actions, &conditions, &mut observer),
+            (Status::Running, 2)
+        );
+    }
+
+    struct Entity {
+        hits: u32,
+    }
+
+    struct EntityActions;
+
+    impl ActionHandler<u32> for EntityActions {
+        fn execute(&mut self, action: &u32, ctx: &mut Context) -> Status {
+            match ctx.user_data::<Entity>() {
+                Some(entity) => {
+                    entity.hits += *action;
+                    Status::Success
+                }
+                None => Status::Failure,
+            }
+        }
+    }
+
+    #[test]
+    fn tree_tick_with_user_data() {
+        let root: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .sequence()
+            .action(1u32)
+            .action(2u32)
+            .end()
+            .build();
+        let mut tree = BehaviorTree::new(root);
+        let mut entity = Entity { hits: 0 };
+        let mut actions = EntityActions;
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+
+        let outcome = tree.tick_with_user_data(
+            1,
+            None,
+            Some(&mut entity),
+            &mut actions,
+            &conditions,
+            &mut observer,
+        );
+        assert_eq!(outcome, Status::Success);
+        assert_eq!(entity.hits, 3);
+        assert_eq!(
+            tree.tick(&mut actions, &conditions, &mut observer),
+            Status::Failure
+        );
+    }
+
+    #[test]
+    fn tree_utility_selector_f64_matches_f32() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::UtilitySelector {
+            children: vec![
+                BehaviorNode::Action(1),
+                BehaviorNode::Action(2),
+                BehaviorNode::Action(3),
+            ],
+            utility_ids: vec![10, 11, 12],
+        };
+        let mut single = BehaviorTree::new(root.clone());
+        let mut double = BehaviorTree::<u32, u32, f64>::with_float(root);
+        for bb in [single.blackboard_mut(), double.blackboard_mut()] {
+            bb.set_float(10, 0.3);
+            bb.set_float(11, 0.8);
+            bb.set_float(12, 0.5);
+        }
+
+        let mut actions = RecordingActions::default();
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+        single.tick(&mut actions, &conditions, &mut observer);
+        double.tick(&mut actions, &conditions, &mut observer);
+        assert_eq!(actions.calls, vec![2, 2]);
+    }
+
+    #[test]
+    fn tree_with_float_over_array_blackboard() {
+        use crate::{
+            ArrayBlackboard, BlackboardStore, BlackboardValue, ClosureActionHandler,
+            ClosureConditionHandler,
+        };
+
+        type Board = ArrayBlackboard<2>;
+        let root: BehaviorNode<u32, u32> = BehaviorNode::UtilitySelector {
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            utility_ids: vec![10, 11],
+        };
+        let mut tree = BehaviorTree::<u32, u32, f64, Board>::with_float(root);
+        tree.blackboard_mut()
+            .set(10, BlackboardValue::from_f32(0.2));
+        tree.blackboard_mut()
+            .set(11, BlackboardValue::from_f32(0.9));
+
+        let mut calls = Vec::new();
+        let mut actions = ClosureActionHandler::new(|action: &u32, _: &mut Context<Board>| {
+            calls.push(*action);
+            Status::Success
+        });
+        let conditions = ClosureConditionHandler::new(|_: &u32, _: &Context<Board>| true);
+        tree.tick(&mut actions, &conditions, &mut NoOpObserver);
+        assert_eq!(calls, vec![2]);
+    }
+
+    #[test]
+    fn tree_label_lookup() {
+        let (root, labels) = TreeBuilder::<u32, u32>::new()
+            .label("root")
+            .selector()
+            .condition(1u32)
+            .label("attack")
+            .action(2u32)
+            .end()
+            .build_with_labels();
+        let mut tree = BehaviorTree::new(root);
+        tree.set_labels(labels);
+        assert_eq!(tree.label_for(0), Some("root"));
+        assert_eq!(tree.label_for(1), None);
+        assert_eq!(tree.label_for(2), Some("attack"));
+    }
+
+    #[test]
+    fn tree_node_path_nested() {
+        let root: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .sequence()
+            .action(1u32)
+            .wait(2)
+            .selector()
+            .condition(3u32)
+            .decorator(Decorator::Inverter)
+            .action(4u32)
+            .end()
+            .end()
+            .build();
+        let tree = BehaviorTree::new(root);
+        assert_eq!(tree.node_path(0), Vec::<usize>::new());
+        assert_eq!(tree.node_path(2), vec![1]);
+        assert_eq!(tree.node_path(5), vec![2, 1]);
+        assert_eq!(tree.node_path(6), vec![2, 1, 0]);
+        assert_eq!(tree.node_path(99), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn tree_node_state_profiling_counters() {
+        let root: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .sequence()
+            .action(1u32)
+            .wait(2)
+            .end()
+            .build();
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = UnitActions;
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+        for _ in 0..4 {
+            let _ = tree.tick(&mut actions, &conditions, &mut observer);
+        }
+
+        // The action runs on ticks 1 and 3 while the wait is entered every tick.
+        assert_eq!(tree.node_state(0).enter_count, 4);
+        assert_eq!(tree.node_state(1).enter_count, 2);
+        assert_eq!(tree.node_state(2).enter_count, 4);
+        assert_eq!(tree.node_state(0).last_status, Some(Status::Success));
+        assert_eq!(tree.node_state(1).last_status, Some(Status::Success));
+
+        tree.reset();
+        assert_eq!(tree.node_state(0).enter_count, 0);
+        assert_eq!(tree.node_state(0).last_status, None);
+    }
+
+    #[test]
+    fn tree_with_owned_rng_ticks_random_selector() {
+        let root: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .random_selector()
+            .action(1u32)
+            .action(2u32)
+            .action(3u32)
+            .end()
+            .build();
+        let mut tree = BehaviorTree::with_rng(root.clone(), SeededRng::new(11));
+        let mut replay = BehaviorTree::with_rng(root, SeededRng::new(11));
+        let mut actions = RecordingActions::default();
+        let mut replay_actions = RecordingActions::default();
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+        for _ in 0..8 {
+            assert_eq!(
+                tree.tick(&mut actions, &conditions, &mut observer),
+                Status::Success
+            );
+            let _ = replay.tick(&mut replay_actions, &conditions, &mut observer);
+        }
+        assert_eq!(actions.calls.len(), 8);
+        assert_eq!(actions.calls, replay_actions.calls);
+    }
+
+    #[test]
+    fn tree_tick_recorded_events() {
+        let root: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .sequence()
+            .condition(1u32)
+            .action(2u32)
+            .end()
+            .build();
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = UnitActions;
+        let (status, events) = tree.tick_recorded(&mut actions, &UnitConditions);
+        assert_eq!(status, Status::Success);
+        assert_eq!(
+            events,
+            vec![
+                ObserverEvent::Enter(0),
+                ObserverEvent::Enter(1),
+                ObserverEvent::Exit(1, Status::Success),
+                ObserverEvent::Enter(2),
+                ObserverEvent::Exit(2, Status::Success),
+                ObserverEvent::Exit(0, Status::Success),
+            ]
+        );
+    }
+
     #[test]
     fn tree_blackboard_access() {
         let root: BehaviorNode<u32, u32> = TreeBuilder::new().sequence().action(1u32).end().build();
@@ -169,4 +1062,593 @@ mod tests {
         tree.blackboard_mut().set_bool(10, true);
         assert_eq!(tree.blackboard().get_bool(10), Some(true));
     }
+
+    #[test]
+    fn tree_replace_root_keeps_state_for_same_shape() {
+        let root: BehaviorNode<u32, u32> =
+            BehaviorNode::Sequence(vec![BehaviorNode::Wait(3), BehaviorNode::Action(1)]);
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = RecordingActions::default();
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+        assert_eq!(
+            tree.tick(&mut actions, &conditions, &mut observer),
+            Status::Running
+        );
+
+        tree.replace_root(BehaviorNode::Sequence(vec![
+            BehaviorNode::Wait(3),
+            BehaviorNode::Action(7),
+        ]));
+        assert_eq!(tree.node_state(1).tick_counter, 1);
+        tree.tick(&mut actions, &conditions, &mut observer);
+        assert_eq!(
+            tree.tick(&mut actions, &conditions, &mut observer),
+            Status::Success
+        );
+        assert_eq!(actions.calls, vec![7]);
+    }
+
+    #[test]
+    fn tree_replace_root_resets_state_for_new_shape() {
+        let root: BehaviorNode<u32, u32> =
+            BehaviorNode::Sequence(vec![BehaviorNode::Wait(3), BehaviorNode::Action(1)]);
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = UnitActions;
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+        tree.tick(&mut actions, &conditions, &mut observer);
+
+        tree.replace_root(BehaviorNode::Sequence(vec![
+            BehaviorNode::Wait(3),
+            BehaviorNode::Action(1),
+            BehaviorNode::Action(2),
+        ]));
+        assert_eq!(tree.node_count(), 4);
+        assert_eq!(tree.node_state(1).tick_counter, 0);
+    }
+
+    #[test]
+    fn tree_wait_millis_uses_delta_millis() {
+        let root: BehaviorNode<u32, u32> = TreeBuilder::new().wait_millis(1000).build();
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = UnitActions;
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+
+        tree.set_delta_millis(400);
+        assert_eq!(
+            tree.tick(&mut actions, &conditions, &mut observer),
+            Status::Running
+        );
+        tree.set_delta_millis(700);
+        assert_eq!(
+            tree.tick(&mut actions, &conditions, &mut observer),
+            Status::Success
+        );
+    }
+
+    #[test]
+    fn validate_tree_rejects_mismatched_utility_selector() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::Sequence(vec![
+            BehaviorNode::Action(0),
+            BehaviorNode::UtilitySelector {
+                children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+                utility_ids: vec![7],
+            },
+        ]);
+
+        assert_eq!(
+            validate_tree(&root),
+            Err(TreeError::UtilityIdCountMismatch {
+                children: 2,
+                ids: 1
+            })
+        );
+        assert!(BehaviorTree::try_new(root).is_err());
+    }
+
+    #[test]
+    fn validate_tree_rejects_mismatched_blackboard_weights() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::BlackboardWeightedSelector {
+            children: vec![BehaviorNode::Action(1)],
+            weight_keys: vec![0, 1, 2],
+            seed: None,
+        };
+
+        assert_eq!(
+            validate_tree(&root),
+            Err(TreeError::WeightCountMismatch {
+                children: 1,
+                weights: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn validate_tree_accepts_valid_tree() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::Selector(vec![
+            BehaviorNode::UtilitySelector {
+                children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+                utility_ids: vec![0, 1],
+            },
+            BehaviorNode::WeightedSelector {
+                children: vec![BehaviorNode::Action(3)],
+                weights: vec![5],
+                seed: None,
+            },
+        ]);
+
+        assert_eq!(validate_tree(&root), Ok(()));
+        let tree = BehaviorTree::try_new(root).unwrap();
+        assert_eq!(tree.node_count(), 6);
+    }
+
+    #[test]
+    fn try_new_rejects_weight_mismatch() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::WeightedSelector {
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            weights: vec![1],
+            seed: None,
+        };
+
+        assert_eq!(
+            BehaviorTree::try_new(root).err(),
+            Some(TreeError::WeightCountMismatch {
+                children: 2,
+                weights: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn try_with_config_checks_depth_and_empty_composites() {
+        let deep: BehaviorNode<u32, u32> = TreeBuilder::new().invert().invert().action(1).build();
+        let shallow = TreeConfig {
+            max_depth: 2,
+            ..TreeConfig::default()
+        };
+        assert_eq!(
+            validate_tree_with(&deep, &shallow),
+            Err(TreeError::MaxDepthExceeded(3))
+        );
+        assert!(BehaviorTree::try_new(deep).is_ok());
+
+        let empty: BehaviorNode<u32, u32> =
+            BehaviorNode::Sequence(vec![BehaviorNode::Selector(vec![])]);
+        assert_eq!(
+            BehaviorTree::try_new(empty.clone()).err(),
+            Some(TreeError::EmptyComposite)
+        );
+        let permissive = TreeConfig {
+            allow_empty_composites: true,
+            ..TreeConfig::default()
+        };
+        assert!(BehaviorTree::try_with_config(empty, &permissive).is_ok());
+    }
+
+    #[test]
+    fn validate_tree_rejects_mismatched_parallel_weights() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::Parallel {
+            policy: ParallelPolicy::RequireWeight {
+                threshold: 2,
+                weights: vec![1, 1, 1],
+            },
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            short_circuit: false,
+        };
+
+        assert_eq!(
+            validate_tree(&root),
+            Err(TreeError::WeightCountMismatch {
+                children: 2,
+                weights: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn validate_tree_rejects_too_many_children() {
+        let wide = |count: u32| -> BehaviorNode<u32, u32> {
+            BehaviorNode::Selector((0..count).map(BehaviorNode::Action).collect())
+        };
+
+        assert_eq!(validate_tree(&wide(MAX_CHILDREN as u32)), Ok(()));
+        assert_eq!(
+            validate_tree(&wide(MAX_CHILDREN as u32 + 1)),
+            Err(TreeError::TooManyChildren(MAX_CHILDREN + 1))
+        );
+    }
+
+    #[test]
+    fn validate_tree_rejects_require_n_above_child_count() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::Parallel {
+            policy: ParallelPolicy::RequireN(5),
+            children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+            short_circuit: false,
+        };
+
+        assert_eq!(
+            validate_tree(&root),
+            Err(TreeError::ParallelThresholdTooHigh {
+                required: 5,
+                children: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn tree_state_round_trips_mid_wait() {
+        let root = || -> BehaviorNode<u32, u32> {
+            BehaviorNode::Sequence(vec![BehaviorNode::Wait(3), BehaviorNode::Action(1)])
+        };
+        let mut tree = BehaviorTree::new(root());
+        let mut actions = RecordingActions::default();
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+        tree.blackboard_mut().set_int(4, 9);
+        tree.tick(&mut actions, &conditions, &mut observer);
+        tree.tick(&mut actions, &conditions, &mut observer);
+        let saved = tree.export_state();
+
+        let mut restored = BehaviorTree::new(root());
+        restored.import_state(saved).unwrap();
+        assert_eq!(restored.tick_count(), 2);
+        assert_eq!(restored.blackboard().get_int(4), Some(9));
+        assert_eq!(
+            restored.tick(&mut actions, &conditions, &mut observer),
+            Status::Success
+        );
+        assert_eq!(actions.calls, vec![1]);
+
+        let mut other = BehaviorTree::new(BehaviorNode::<u32, u32>::Action(1));
+        assert_eq!(
+            other.import_state(tree.export_state()),
+            Err(TreeError::StateSizeMismatch {
+                expected: 1,
+                actual: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn tree_active_leaves_follow_running_branch() {
+        let root: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .selector()
+            .sequence()
+            .condition(0)
+            .wait(3)
+            .action(1)
+            .end()
+            .action(2)
+            .end()
+            .build();
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = UnitActions;
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+
+        assert!(tree.active_leaves().is_empty());
+        tree.tick(&mut actions, &conditions, &mut observer);
+        assert_eq!(tree.active_leaves(), vec![3]);
+
+        tree.states[3].last_enter_tick = 0;
+        assert_eq!(tree.active_leaves(), vec![1]);
+        tree.states[3].last_enter_tick = tree.tick_count;
+
+        tree.reset();
+        tree.tick(&mut actions, &conditions, &mut observer);
+        tree.tick(&mut actions, &conditions, &mut observer);
+        tree.tick(&mut actions, &conditions, &mut observer);
+        assert!(tree.active_leaves().is_empty());
+    }
+
+    #[test]
+    fn tree_tick_tracing_reports_parallel_running_children() {
+        let root: BehaviorNode<u32, u32> = TreeBuilder::new()
+            .sequence()
+            .action(1)
+            .parallel(ParallelPolicy::RequireAll)
+            .wait(2)
+            .action(2)
+            .wait(2)
+            .end()
+            .end()
+            .build();
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = UnitActions;
+        let conditions = UnitConditions;
+        let mut observer = RecordingObserver::default();
+
+        let (status, path) = tree.tick_tracing(&mut actions, &conditions, &mut observer);
+        assert_eq!(status, Status::Running);
+        assert_eq!(path, vec![0, 2, 3, 5]);
+        assert!(observer
+            .events
+            .contains(&ObserverEvent::Exit(4, Status::Success)));
+
+        let (status, path) = tree.tick_tracing(&mut actions, &conditions, &mut observer);
+        assert_eq!(status, Status::Success);
+        assert!(path.is_empty());
+    }
+
+    #[test]
+    fn tree_config_sets_empty_composite_statuses() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::Selector(vec![
+            BehaviorNode::Sequence(vec![]),
+            BehaviorNode::Action(1),
+        ]);
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = RecordingActions::default();
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+
+        assert_eq!(
+            tree.tick(&mut actions, &conditions, &mut observer),
+            Status::Success
+        );
+        assert!(actions.calls.is_empty());
+
+        tree.set_config(TreeConfig {
+            empty_sequence_status: Status::Failure,
+            ..TreeConfig::default()
+        });
+        assert_eq!(
+            tree.tick(&mut actions, &conditions, &mut observer),
+            Status::Success
+        );
+        assert_eq!(actions.calls, vec![1]);
+    }
+
+    #[test]
+    fn tree_config_empty_selector_status() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::Selector(vec![]);
+        let permissive = TreeConfig {
+            allow_empty_composites: true,
+            empty_selector_status: Status::Running,
+            ..TreeConfig::default()
+        };
+        let mut tree = BehaviorTree::try_with_config(root, &permissive).unwrap();
+        let mut actions = UnitActions;
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+
+        assert_eq!(
+            tree.tick(&mut actions, &conditions, &mut observer),
+            Status::Running
+        );
+        tree.set_config(TreeConfig::default());
+        assert_eq!(
+            tree.tick(&mut actions, &conditions, &mut observer),
+            Status::Failure
+        );
+    }
+
+    #[test]
+    fn tree_subtree_ref_ticks_library_subtree() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::Sequence(vec![
+            BehaviorNode::Action(1),
+            BehaviorNode::SubtreeRef(7),
+            BehaviorNode::SubtreeRef(7),
+        ]);
+        let mut tree = BehaviorTree::new(root);
+        tree.set_library(SubtreeLibrary::new().with(
+            7,
+            BehaviorNode::Sequence(vec![BehaviorNode::Wait(2), BehaviorNode::Action(2)]),
+        ));
+        let mut actions = RecordingActions::default();
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+
+        let statuses: Vec<Status> = (0..3)
+            .map(|_| tree.tick(&mut actions, &conditions, &mut observer).status)
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![Status::Running, Status::Running, Status::Success]
+        );
+        assert_eq!(actions.calls, vec![1, 2, 2]);
+        assert_eq!(tree.node_count(), 4);
+        assert_eq!(tree.node_state(2).extra().unwrap().subtree_states.len(), 3);
+    }
+
+    #[test]
+    fn tree_unresolved_subtree_ref_fails() {
+        let root: BehaviorNode<u32, u32> =
+            BehaviorNode::Selector(vec![BehaviorNode::SubtreeRef(3), BehaviorNode::Action(1)]);
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = RecordingActions::default();
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+
+        assert_eq!(
+            tree.tick(&mut actions, &conditions, &mut observer),
+            Status::Success
+        );
+        assert_eq!(tree.node_state(1).last_status, Some(Status::Failure));
+        assert_eq!(actions.calls, vec![1]);
+    }
+
+    #[test]
+    fn tree_recursive_subtree_ref_fails_at_depth_cap() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::SubtreeRef(1);
+        let mut tree = BehaviorTree::new(root);
+        tree.set_library(
+            SubtreeLibrary::new()
+                .with(
+                    1,
+                    BehaviorNode::Selector(vec![
+                        BehaviorNode::SubtreeRef(2),
+                        BehaviorNode::Action(1),
+                    ]),
+                )
+                .with(2, BehaviorNode::SubtreeRef(1)),
+        );
+        let mut actions = RecordingActions::default();
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+
+        assert_eq!(
+            tree.tick(&mut actions, &conditions, &mut observer),
+            Status::Success
+        );
+        assert_eq!(actions.calls, vec![1]);
+
+        let mut depth = 0;
+        let mut nested = &tree.node_state(0).extra().unwrap().subtree_states[..];
+        while !nested.is_empty() {
+            depth += 1;
+            let ref_id = if nested.len() == 1 { 0 } else { 1 };
+            nested = nested[ref_id]
+                .extra()
+                .map_or(&[][..], |e| &e.subtree_states);
+        }
+        assert_eq!(depth, MAX_SUBTREE_DEPTH);
+    }
+
+    #[test]
+    fn paused_tree_skips_ticks() {
+        let root = BehaviorNode::Sequence(vec![BehaviorNode::Action(1), BehaviorNode::Action(2)]);
+        let mut tree = BehaviorTree::new(root);
+        let mut actions = RecordingActions { calls: Vec::new() };
+        let conditions = UnitConditions;
+        let mut observer = NoOpObserver;
+
+        tree.set_paused(true);
+        let outcome = tree.tick(&mut actions, &conditions, &mut observer);
+        assert_eq!(outcome.status, Status::Running);
+
+        tree.set_paused(false);
+        tree.tick(&mut actions, &conditions, &mut observer);
+        assert_eq!(actions.calls, vec![1, 2]);
+
+        tree.set_paused(true);
+        for _ in 0..3 {
+            let outcome = tree.tick(&mut actions, &conditions, &mut observer);
+            assert_eq!(outcome.status, Status::Success);
+        }
+        assert!(tree.is_paused());
+        assert_eq!(tree.tick_count(), 1);
+        assert_eq!(actions.calls, vec![1, 2]);
+    }
+
+    #[test]
+    fn trees_are_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<BehaviorTree<u32, u32>>();
+        assert_send::<crate::SharedTree<u32, u32>>();
+        assert_send::<crate::AgentState>();
+    }
+
+    #[test]
+    fn tree_ticks_against_array_blackboard() {
+        use crate::{
+            ArrayBlackboard, BlackboardStore, BlackboardValue, ClosureActionHandler,
+            ClosureConditionHandler,
+        };
+
+        let root: BehaviorNode<u32, u32> = BehaviorNode::Sequence(vec![
+            BehaviorNode::SetBlackboard {
+                key: 1,
+                value: BlackboardValue::Bool(true),
+            },
+            BehaviorNode::BlackboardCondition(1),
+            BehaviorNode::SetBlackboard {
+                key: 2,
+                value: BlackboardValue::Int(4),
+            },
+        ]);
+        let mut tree = BehaviorTree::with_blackboard(root, ArrayBlackboard::<1>::new());
+        let mut actions = ClosureActionHandler::new(|_: &u32, _: &mut Context<_>| Status::Success);
+        let conditions = ClosureConditionHandler::new(|_: &u32, _: &Context<_>| true);
+        let status = tree
+            .tick(&mut actions, &conditions, &mut NoOpObserver)
+            .status;
+
+        // The second write doesn't fit in a one-entry store.
+        assert_eq!(status, Status::Failure);
+        assert_eq!(tree.blackboard().get(1), Some(BlackboardValue::Bool(true)));
+        assert_eq!(tree.blackboard().len(), 1);
+    }
+
+    #[test]
+    fn tree_combines_float_blackboard_validation_and_rng() {
+        use crate::{
+            ArrayBlackboard, BlackboardStore, BlackboardValue, ClosureActionHandler,
+            ClosureConditionHandler, SeededRng,
+        };
+
+        type Board = ArrayBlackboard<2>;
+        type Tree = BehaviorTree<u32, u32, f64, Board>;
+        let config = TreeConfig::default();
+        let empty: BehaviorNode<u32, u32> = BehaviorNode::Sequence(vec![]);
+        assert!(Tree::try_with_float_and_blackboard(empty, Board::new(), &config).is_err());
+
+        let write = |value| BehaviorNode::SetBlackboard {
+            key: 1,
+            value: BlackboardValue::Int(value),
+        };
+        let root = BehaviorNode::RandomSelector {
+            children: vec![write(1), write(2)],
+            seed: None,
+        };
+        let mut tree = Tree::try_with_float_and_blackboard(root, Board::new(), &config).unwrap();
+        tree.set_rng(SeededRng::new(3));
+        let mut actions =
+            ClosureActionHandler::new(|_: &u32, _: &mut Context<Board>| Status::Success);
+        let conditions = ClosureConditionHandler::new(|_: &u32, _: &Context<Board>| true);
+
+        let status = tree
+            .tick(&mut actions, &conditions, &mut NoOpObserver)
+            .status;
+        assert_eq!(status, Status::Success);
+        assert!(tree.blackboard().has(1));
+    }
+
+    #[derive(Default)]
+    struct RunningActions {
+        calls: Vec<u32>,
+        aborted: Vec<u32>,
+    }
+
+    impl ActionHandler<u32> for RunningActions {
+        fn execute(&mut self, action: &u32, _ctx: &mut Context) -> Status {
+            self.calls.push(*action);
+            Status::Running
+        }
+
+        fn on_abort(&mut self, action: &u32, _ctx: &mut Context) {
+            self.aborted.push(*action);
+        }
+    }
+
+    #[test]
+    fn tree_leaf_budget_preemption_aborts_only_executed_actions() {
+        let root: BehaviorNode<u32, u32> = BehaviorNode::ReactiveSelector(vec![
+            BehaviorNode::BlackboardCondition(9),
+            BehaviorNode::Parallel {
+                policy: ParallelPolicy::RequireAll,
+                children: vec![BehaviorNode::Action(1), BehaviorNode::Action(2)],
+                short_circuit: false,
+            },
+        ]);
+        let mut tree = BehaviorTree::new(root);
+        tree.set_leaf_budget(Some(2));
+        let mut actions = RunningActions::default();
+        let conditions = UnitConditions;
+
+        let (status, path) = tree.tick_tracing(&mut actions, &conditions, &mut NoOpObserver);
+        assert_eq!(status, Status::Running);
+        assert_eq!(path, vec![0, 2, 3]);
+        assert_eq!(tree.active_leaves(), vec![3]);
+        assert_eq!(tree.node_state(4).last_status, None);
+
+        tree.blackboard_mut().set_bool(9, true);
+        let status = tree
+            .tick(&mut actions, &conditions, &mut NoOpObserver)
+            .status;
+        assert_eq!(status, Status::Success);
+        assert_eq!(actions.calls, vec![1]);
+        assert_eq!(actions.aborted, vec![1]);
+    }
 }
@@ -1,6 +1,6 @@
 use alloc::vec::Vec;
 
-use crate::blackboard::Blackboard;
+use crate::blackboard::BlackboardStore;
 use crate::float::Float;
 use crate::utility::consideration::Consideration;
 
@@ -13,14 +13,41 @@ pub struct UtilityAction<F: Float, A> {
 }
 
 impl<F: Float, A> UtilityAction<F, A> {
-    pub fn score(&self, blackboard: &Blackboard, is_current: bool) -> F {
+    /// Starts an action with weight 1.0, no momentum and no considerations.
+    pub fn builder(action_id: A) -> UtilityActionBuilder<F, A> {
+        UtilityActionBuilder {
+            action: UtilityAction {
+                action_id,
+                considerations: Vec::new(),
+                weight: F::one(),
+                momentum: F::zero(),
+            },
+        }
+    }
+
+    pub fn score(&self, blackboard: &impl BlackboardStore, is_current: bool) -> F {
+        self.score_with(is_current, |consideration| {
+            consideration.evaluate(blackboard)
+        })
+    }
+
+    /// Scores using `evaluate` for each consideration; a zero vetoes the action without
+    /// evaluating the remaining considerations.
+    pub(crate) fn score_with(
+        &self,
+        is_current: bool,
+        mut evaluate: impl FnMut(&Consideration<F>) -> F,
+    ) -> F {
         if self.considerations.is_empty() {
             return self.weight;
         }
 
         let mut product = F::one();
         for consideration in &self.considerations {
-            product = product * consideration.evaluate(blackboard);
+            product = product * evaluate(consideration);
+            if product == F::zero() {
+                return F::zero();
+            }
         }
 
         let n = self.considerations.len();
@@ -36,13 +63,38 @@ impl<F: Float, A> UtilityAction<F, A> {
     }
 }
 
+pub struct UtilityActionBuilder<F: Float, A> {
+    action: UtilityAction<F, A>,
+}
+
+impl<F: Float, A> UtilityActionBuilder<F, A> {
+    pub fn weight(mut self, weight: F) -> Self {
+        self.action.weight = weight;
+        self
+    }
+
+    pub fn momentum(mut self, momentum: F) -> Self {
+        self.action.momentum = momentum;
+        self
+    }
+
+    pub fn add_consideration(mut self, consideration: Consideration<F>) -> Self {
+        self.action.considerations.push(consideration);
+        self
+    }
+
+    pub fn build(self) -> UtilityAction<F, A> {
+        self.action
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::vec;
 
     use crate::blackboard::Blackboard;
     use crate::utility::action::UtilityAction;
-    use crate::utility::consideration::Consideration;
+    use crate::utility::consideration::{ClampMode, Consideration};
     use crate::utility::curve::ResponseCurve;
 
     fn approx_eq(left: f32, right: f32) {
@@ -59,6 +111,10 @@ mod tests {
             weight: 1.0,
             input_min: 0.0,
             input_max: 1.0,
+            clamp_mode: ClampMode::Clamp,
+            invert: false,
+            bias: 0.0,
+            clamp_output: false,
         }
     }
 
@@ -114,4 +170,57 @@ mod tests {
         };
         approx_eq(action.score(&bb, false), 0.7);
     }
+
+    #[test]
+    fn utility_action_veto_skips_remaining_considerations() {
+        let mut bb = Blackboard::new();
+        bb.set_float(1, 0.0);
+        bb.set_float(2, 0.9);
+        bb.set_float(3, 0.9);
+        let action = UtilityAction {
+            action_id: 1u32,
+            considerations: vec![
+                linear_consideration(1),
+                linear_consideration(2),
+                linear_consideration(3),
+            ],
+            weight: 1.0,
+            momentum: 0.5,
+        };
+
+        let mut evaluated = 0;
+        let score = action.score_with(true, |c| {
+            evaluated += 1;
+            c.evaluate(&bb)
+        });
+        approx_eq(score, 0.0);
+        assert_eq!(evaluated, 1);
+    }
+
+    #[test]
+    fn utility_action_builder_defaults_and_overrides() {
+        let action = UtilityAction::<f32, u32>::builder(7)
+            .momentum(0.1)
+            .add_consideration(Consideration::builder(1).build())
+            .add_consideration(
+                Consideration::builder(2)
+                    .curve(ResponseCurve::Constant(0.5))
+                    .weight(0.8)
+                    .range(10.0, 20.0)
+                    .invert()
+                    .build(),
+            )
+            .build();
+
+        assert_eq!(action.action_id, 7);
+        assert_eq!(action.weight, 1.0);
+        assert_eq!(action.momentum, 0.1);
+        assert_eq!(action.considerations[0], linear_consideration(1));
+        let second = &action.considerations[1];
+        assert_eq!(second.curve, ResponseCurve::Constant(0.5));
+        assert_eq!(second.weight, 0.8);
+        assert_eq!((second.input_min, second.input_max), (10.0, 20.0));
+        assert!(second.invert);
+        assert_eq!(second.clamp_mode, ClampMode::Clamp);
+    }
 }
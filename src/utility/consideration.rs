@@ -1,7 +1,16 @@
-use crate::blackboard::Blackboard;
+use crate::blackboard::BlackboardStore;
 use crate::float::Float;
 use crate::utility::curve::ResponseCurve;
 
+/// How a normalized input outside `[0, 1]` is brought back before the curve sees it.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ClampMode {
+    #[default]
+    Clamp,
+    Wrap,
+    Extend,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Consideration<F: Float> {
     pub input_key: u32,
@@ -9,31 +18,147 @@ pub struct Consideration<F: Float> {
     pub weight: F,
     pub input_min: F,
     pub input_max: F,
+    pub clamp_mode: ClampMode,
+    /// Evaluate the curve on `1 - normalized`, so lower inputs score higher.
+    pub invert: bool,
+    /// Flat amount added after the curve and weight.
+    pub bias: F,
+    /// Clamp the biased score back into `[0, 1]`.
+    pub clamp_output: bool,
 }
 
 impl<F: Float> Consideration<F> {
-    pub fn evaluate(&self, blackboard: &Blackboard) -> F {
-        let raw = match blackboard.get(self.input_key) {
-            Some(value) => F::from_f32(value.to_score_f32()),
-            None => return F::zero(),
-        };
+    /// Starts a consideration over `input_key` with a linear identity curve on `[0, 1]`.
+    pub fn builder(input_key: u32) -> ConsiderationBuilder<F> {
+        ConsiderationBuilder {
+            consideration: Consideration {
+                input_key,
+                curve: ResponseCurve::Linear {
+                    slope: F::one(),
+                    offset: F::zero(),
+                },
+                weight: F::one(),
+                input_min: F::zero(),
+                input_max: F::one(),
+                clamp_mode: ClampMode::Clamp,
+                invert: false,
+                bias: F::zero(),
+                clamp_output: false,
+            },
+        }
+    }
 
+    pub fn evaluate(&self, blackboard: &impl BlackboardStore) -> F {
+        match blackboard.get(self.input_key) {
+            Some(value) => self.shape(self.normalize(F::from_f32(value.to_score_f32()))),
+            None => F::zero(),
+        }
+    }
+
+    /// Scores already-extracted raw inputs, matching [`evaluate`](Self::evaluate) per element.
+    /// Normalization and curve shaping run as separate passes over `out` so the simple loops
+    /// can vectorize. Panics if the slices differ in length.
+    pub fn evaluate_batch(&self, inputs: &[F], out: &mut [F]) {
+        assert_eq!(
+            inputs.len(),
+            out.len(),
+            "batch input and output lengths differ"
+        );
+        for (slot, raw) in out.iter_mut().zip(inputs) {
+            *slot = self.normalize(*raw);
+        }
+        for slot in out.iter_mut() {
+            *slot = self.shape(*slot);
+        }
+    }
+
+    /// Maps a raw input onto the curve's domain per `input_min`/`input_max`, `clamp_mode` and
+    /// `invert`.
+    fn normalize(&self, raw: F) -> F {
         let range = self.input_max - self.input_min;
-        let normalized = if range.abs() <= F::from_f32(1.0e-6) {
+        let normalized = if range.abs() <= F::epsilon() {
             F::zero()
         } else {
             (raw - self.input_min) / range
+        };
+
+        let input = match self.clamp_mode {
+            ClampMode::Clamp => normalized.clamp(F::zero(), F::one()),
+            ClampMode::Wrap => normalized - normalized.floor(),
+            ClampMode::Extend => normalized,
+        };
+        if self.invert {
+            F::one() - input
+        } else {
+            input
         }
-        .clamp(F::zero(), F::one());
+    }
 
-        self.curve.evaluate(normalized) * self.weight
+    /// Applies the curve, weight, bias and output clamp to a normalized input.
+    fn shape(&self, input: F) -> F {
+        let score = match self.clamp_mode {
+            ClampMode::Extend => self.curve.evaluate_unclamped(input),
+            _ => self.curve.evaluate(input),
+        };
+        let biased = score * self.weight + self.bias;
+        if self.clamp_output {
+            biased.clamp(F::zero(), F::one())
+        } else {
+            biased
+        }
+    }
+}
+
+pub struct ConsiderationBuilder<F: Float> {
+    consideration: Consideration<F>,
+}
+
+impl<F: Float> ConsiderationBuilder<F> {
+    pub fn curve(mut self, curve: ResponseCurve<F>) -> Self {
+        self.consideration.curve = curve;
+        self
+    }
+
+    pub fn weight(mut self, weight: F) -> Self {
+        self.consideration.weight = weight;
+        self
+    }
+
+    pub fn range(mut self, min: F, max: F) -> Self {
+        self.consideration.input_min = min;
+        self.consideration.input_max = max;
+        self
+    }
+
+    pub fn clamp_mode(mut self, clamp_mode: ClampMode) -> Self {
+        self.consideration.clamp_mode = clamp_mode;
+        self
+    }
+
+    pub fn invert(mut self) -> Self {
+        self.consideration.invert = true;
+        self
+    }
+
+    pub fn bias(mut self, bias: F) -> Self {
+        self.consideration.bias = bias;
+        self
+    }
+
+    pub fn clamp_output(mut self) -> Self {
+        self.consideration.clamp_output = true;
+        self
+    }
+
+    pub fn build(self) -> Consideration<F> {
+        self.consideration
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::blackboard::Blackboard;
-    use crate::utility::consideration::Consideration;
+    use crate::utility::consideration::{ClampMode, Consideration};
     use crate::utility::curve::ResponseCurve;
 
     fn approx_eq(left: f32, right: f32) {
@@ -53,6 +178,10 @@ mod tests {
             weight: 1.0,
             input_min: 0.0,
             input_max: 1.0,
+            clamp_mode: ClampMode::Clamp,
+            invert: false,
+            bias: 0.0,
+            clamp_output: false,
         };
         approx_eq(c.evaluate(&bb), 0.5);
     }
@@ -66,6 +195,10 @@ mod tests {
             weight: 1.0,
             input_min: 0.0,
             input_max: 1.0,
+            clamp_mode: ClampMode::Clamp,
+            invert: false,
+            bias: 0.0,
+            clamp_output: false,
         };
         approx_eq(c.evaluate(&bb), 0.0);
     }
@@ -82,6 +215,10 @@ mod tests {
             weight: 1.0,
             input_min: 0.0,
             input_max: 100.0,
+            clamp_mode: ClampMode::Clamp,
+            invert: false,
+            bias: 0.0,
+            clamp_output: false,
         };
         approx_eq(c.evaluate(&bb), 0.0);
     }
@@ -99,7 +236,178 @@ mod tests {
             weight: 1.0,
             input_min: 0.0,
             input_max: 100.0,
+            clamp_mode: ClampMode::Clamp,
+            invert: false,
+            bias: 0.0,
+            clamp_output: false,
+        };
+        approx_eq(c.evaluate(&bb), 0.5);
+    }
+
+    #[test]
+    fn consideration_degenerate_range_returns_zero() {
+        let mut bb = Blackboard::new();
+        bb.set_float(1, 0.5);
+        let c32 = Consideration {
+            input_key: 1,
+            curve: ResponseCurve::Linear {
+                slope: 1.0f32,
+                offset: 0.0,
+            },
+            weight: 1.0,
+            input_min: 0.5,
+            input_max: 0.5,
+            clamp_mode: ClampMode::Clamp,
+            invert: false,
+            bias: 0.0,
+            clamp_output: false,
+        };
+        approx_eq(c32.evaluate(&bb), 0.0);
+
+        let c64 = Consideration {
+            input_key: 1,
+            curve: ResponseCurve::Linear {
+                slope: 1.0f64,
+                offset: 0.0,
+            },
+            weight: 1.0,
+            input_min: 0.5,
+            input_max: 0.5,
+            clamp_mode: ClampMode::Clamp,
+            invert: false,
+            bias: 0.0,
+            clamp_output: false,
+        };
+        assert_eq!(c64.evaluate(&bb), 0.0);
+    }
+
+    #[test]
+    fn consideration_wrap_mode() {
+        let mut bb = Blackboard::new();
+        bb.set_float(1, 1.25);
+        let c = Consideration {
+            input_key: 1,
+            curve: ResponseCurve::Linear {
+                slope: 1.0,
+                offset: 0.0,
+            },
+            weight: 1.0,
+            input_min: 0.0,
+            input_max: 1.0,
+            clamp_mode: ClampMode::Wrap,
+            invert: false,
+            bias: 0.0,
+            clamp_output: false,
+        };
+        approx_eq(c.evaluate(&bb), 0.25);
+        bb.set_float(1, -0.25);
+        approx_eq(c.evaluate(&bb), 0.75);
+    }
+
+    #[test]
+    fn consideration_extend_mode() {
+        let mut bb = Blackboard::new();
+        bb.set_float(1, 150.0);
+        let mut c = Consideration {
+            input_key: 1,
+            curve: ResponseCurve::Linear {
+                slope: 0.5,
+                offset: 0.0,
+            },
+            weight: 1.0,
+            input_min: 0.0,
+            input_max: 100.0,
+            clamp_mode: ClampMode::Extend,
+            invert: false,
+            bias: 0.0,
+            clamp_output: false,
         };
+        approx_eq(c.evaluate(&bb), 0.75);
+        c.clamp_mode = ClampMode::Clamp;
         approx_eq(c.evaluate(&bb), 0.5);
     }
+
+    #[test]
+    fn consideration_invert() {
+        let mut bb = Blackboard::new();
+        bb.set_float(1, 0.2);
+        let c = Consideration {
+            input_key: 1,
+            curve: ResponseCurve::Linear {
+                slope: 1.0,
+                offset: 0.0,
+            },
+            weight: 1.0,
+            input_min: 0.0,
+            input_max: 1.0,
+            clamp_mode: ClampMode::Clamp,
+            invert: true,
+            bias: 0.0,
+            clamp_output: false,
+        };
+        approx_eq(c.evaluate(&bb), 0.8);
+    }
+
+    #[test]
+    fn consideration_bias_shifts_score() {
+        let mut bb = Blackboard::new();
+        bb.set_float(1, 0.3);
+        let mut c = Consideration {
+            input_key: 1,
+            curve: ResponseCurve::Linear {
+                slope: 1.0,
+                offset: 0.0,
+            },
+            weight: 1.0,
+            input_min: 0.0,
+            input_max: 1.0,
+            clamp_mode: ClampMode::Clamp,
+            invert: false,
+            bias: 0.2,
+            clamp_output: false,
+        };
+        approx_eq(c.evaluate(&bb), 0.5);
+
+        c.bias = 0.9;
+        approx_eq(c.evaluate(&bb), 1.2);
+        c.clamp_output = true;
+        approx_eq(c.evaluate(&bb), 1.0);
+    }
+
+    #[test]
+    fn consideration_batch_matches_evaluate() {
+        let considerations = [
+            Consideration::builder(1)
+                .curve(ResponseCurve::Logistic {
+                    midpoint: 0.5,
+                    steepness: 8.0,
+                })
+                .range(-10.0, 30.0)
+                .build(),
+            Consideration::builder(1)
+                .curve(ResponseCurve::Polynomial {
+                    exponent: 2.0,
+                    offset: 0.0,
+                })
+                .range(0.0, 10.0)
+                .clamp_mode(ClampMode::Wrap)
+                .invert()
+                .bias(0.1)
+                .build(),
+            Consideration::builder(1)
+                .range(0.0, 20.0)
+                .clamp_mode(ClampMode::Extend)
+                .build(),
+        ];
+        let inputs = [-15.0f32, -2.5, 0.0, 3.3, 9.99, 12.0, 27.5, 40.0];
+        let mut out = [0.0f32; 8];
+        for c in &considerations {
+            c.evaluate_batch(&inputs, &mut out);
+            for (raw, got) in inputs.iter().zip(out) {
+                let mut bb = Blackboard::new();
+                bb.set_real_float(1, *raw);
+                assert_eq!(got, c.evaluate(&bb), "raw={raw}");
+            }
+        }
+    }
 }
@@ -1,21 +1,43 @@
+use alloc::boxed::Box;
 use alloc::vec::Vec;
 
 use crate::float::Float;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum ResponseCurve<F: Float> {
-    Linear { slope: F, offset: F },
-    Polynomial { exponent: F, offset: F },
-    Logistic { midpoint: F, steepness: F },
-    Step { threshold: F },
-    Inverse { offset: F },
+    Linear {
+        slope: F,
+        offset: F,
+    },
+    Polynomial {
+        exponent: F,
+        offset: F,
+    },
+    Logistic {
+        midpoint: F,
+        steepness: F,
+    },
+    Step {
+        threshold: F,
+    },
+    Inverse {
+        offset: F,
+    },
     Constant(F),
     CustomPoints(Vec<(F, F)>),
+    /// Interpolates from the first curve's output (`t = 0`) to the second's (`t = 1`).
+    Blend(Box<ResponseCurve<F>>, Box<ResponseCurve<F>>, F),
+    /// `1 - inner`, turning "more is better" into "less is better".
+    Inverted(Box<ResponseCurve<F>>),
 }
 
 impl<F: Float> ResponseCurve<F> {
     pub fn evaluate(&self, x: F) -> F {
-        let x = x.clamp(F::zero(), F::one());
+        self.evaluate_unclamped(x.clamp(F::zero(), F::one()))
+    }
+
+    /// Evaluates without clamping `x` to `[0, 1]` first; the output is still clamped.
+    pub fn evaluate_unclamped(&self, x: F) -> F {
         let raw = match self {
             ResponseCurve::Linear { slope, offset } => *slope * x + *offset,
             ResponseCurve::Polynomial { exponent, offset } => {
@@ -45,10 +67,27 @@ impl<F: Float> ResponseCurve<F> {
             }
             ResponseCurve::Constant(v) => *v,
             ResponseCurve::CustomPoints(points) => piecewise_lerp(points, x),
+            ResponseCurve::Blend(a, b, t) => {
+                a.evaluate_unclamped(x).lerp(b.evaluate_unclamped(x), *t)
+            }
+            ResponseCurve::Inverted(inner) => F::one() - inner.evaluate_unclamped(x),
         };
 
         raw.clamp(F::zero(), F::one())
     }
+
+    /// Evaluates `n` evenly spaced points across `[0, 1]` as `(x, y)` pairs, e.g. for plotting.
+    /// Fewer than two points still yields both endpoints.
+    pub fn sample(&self, n: usize) -> Vec<(F, F)> {
+        let n = n.max(2);
+        let last = (n - 1) as f32;
+        (0..n)
+            .map(|i| {
+                let x = F::from_f32(i as f32 / last);
+                (x, self.evaluate(x))
+            })
+            .collect()
+    }
 }
 
 fn piecewise_lerp<F: Float>(points: &[(F, F)], x: F) -> F {
@@ -85,6 +124,7 @@ fn piecewise_lerp<F: Float>(points: &[(F, F)], x: F) -> F {
 
 #[cfg(test)]
 mod tests {
+    use alloc::boxed::Box;
     use alloc::vec;
 
     use super::ResponseCurve;
@@ -182,4 +222,53 @@ mod tests {
         };
         assert_eq!(curve.evaluate(1.0), 1.0);
     }
+
+    #[test]
+    fn curve_sample_even_points() {
+        let curve = ResponseCurve::Linear {
+            slope: 1.0,
+            offset: 0.0,
+        };
+        let samples = curve.sample(5);
+        assert_eq!(samples.len(), 5);
+        for ((x, y), expected) in samples.iter().zip([0.0, 0.25, 0.5, 0.75, 1.0]) {
+            approx_eq(*x, expected);
+            approx_eq(*y, expected);
+        }
+
+        let endpoints = ResponseCurve::Constant(0.3f32).sample(0);
+        assert_eq!(endpoints, vec![(0.0, 0.3), (1.0, 0.3)]);
+    }
+
+    #[test]
+    fn curve_blend_interpolates_outputs() {
+        let curve = ResponseCurve::Blend(
+            Box::new(ResponseCurve::Constant(0.0f32)),
+            Box::new(ResponseCurve::Constant(1.0)),
+            0.5,
+        );
+        for (_, y) in curve.sample(11) {
+            approx_eq(y, 0.5);
+        }
+
+        let curve = ResponseCurve::Blend(
+            Box::new(ResponseCurve::Linear {
+                slope: 1.0,
+                offset: 0.0,
+            }),
+            Box::new(ResponseCurve::Constant(1.0)),
+            0.25,
+        );
+        approx_eq(curve.evaluate(0.2), 0.4);
+    }
+
+    #[test]
+    fn curve_inverted_step() {
+        let curve = ResponseCurve::Inverted(Box::new(ResponseCurve::Step { threshold: 0.5f32 }));
+        approx_eq(curve.evaluate(0.25), 1.0);
+        approx_eq(curve.evaluate(0.75), 0.0);
+
+        let doubled = ResponseCurve::Inverted(Box::new(curve));
+        approx_eq(doubled.evaluate(0.75), 1.0);
+    }
 }
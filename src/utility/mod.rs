@@ -3,7 +3,7 @@ pub mod consideration;
 pub mod curve;
 pub mod reasoner;
 
-pub use action::UtilityAction;
-pub use consideration::Consideration;
+pub use action::{UtilityAction, UtilityActionBuilder};
+pub use consideration::{ClampMode, Consideration, ConsiderationBuilder};
 pub use curve::ResponseCurve;
-pub use reasoner::{Reasoner, SelectionMethod};
+pub use reasoner::{CachedReasoner, Reasoner, SelectionMethod};
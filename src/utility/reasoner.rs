@@ -1,14 +1,19 @@
+use alloc::collections::BTreeSet;
+use alloc::vec;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
 
 use rand_core::RngCore;
 
-use crate::blackboard::Blackboard;
+use crate::blackboard::BlackboardStore;
 use crate::float::Float;
+use crate::observer::Observer;
 use crate::utility::action::UtilityAction;
+use crate::utility::consideration::Consideration;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum SelectionMethod {
+    /// Picks the best score; ties prefer the current action, then the lowest index.
     HighestScore,
     WeightedRandom,
     TopN(usize),
@@ -23,82 +28,282 @@ pub struct Reasoner<F: Float, A> {
 impl<F: Float, A> Reasoner<F, A> {
     pub fn select(
         &self,
-        blackboard: &Blackboard,
+        blackboard: &impl BlackboardStore,
         current_action: Option<usize>,
         rng: Option<&mut dyn RngCore>,
+    ) -> usize {
+        self.select_with(
+            blackboard,
+            current_action,
+            rng,
+            &mut Vec::new(),
+            &mut Vec::new(),
+        )
+    }
+
+    /// Like `select`, scoring into caller-owned buffers so repeated calls don't allocate.
+    pub fn select_with(
+        &self,
+        blackboard: &impl BlackboardStore,
+        current_action: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+        scores: &mut Vec<(usize, F)>,
+        order: &mut Vec<usize>,
+    ) -> usize {
+        if self.actions.is_empty() {
+            return 0;
+        }
+
+        self.score_into(blackboard, current_action, scores);
+        self.choose(scores, current_action, order, rng)
+    }
+
+    /// Like `select`, reporting each action's score to `observer` in index order first, as
+    /// `UtilitySelector` does inside a tree.
+    pub fn select_observed(
+        &self,
+        blackboard: &impl BlackboardStore,
+        current_action: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+        observer: &mut impl Observer,
     ) -> usize {
         if self.actions.is_empty() {
             return 0;
         }
 
-        let scores: Vec<F> = self
-            .actions
-            .iter()
-            .enumerate()
-            .map(|(i, action)| action.score(blackboard, current_action == Some(i)))
-            .collect();
+        let mut scores = Vec::new();
+        self.score_into(blackboard, current_action, &mut scores);
+        for (i, score) in &scores {
+            observer.on_utility_score(*i, score.to_f32());
+        }
+        self.choose(&scores, current_action, &mut Vec::new(), rng)
+    }
 
+    /// Like `select`, but returns `None` when no action scores at least `min_score`.
+    pub fn select_above(
+        &self,
+        blackboard: &impl BlackboardStore,
+        current_action: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+        min_score: F,
+    ) -> Option<usize> {
+        let mut candidates = Vec::new();
+        self.score_into(blackboard, current_action, &mut candidates);
+        candidates.retain(|(_, score)| *score >= min_score);
+        if candidates.is_empty() {
+            return None;
+        }
+
+        Some(self.choose(&candidates, current_action, &mut Vec::new(), rng))
+    }
+
+    fn score_into(
+        &self,
+        blackboard: &impl BlackboardStore,
+        current_action: Option<usize>,
+        scores: &mut Vec<(usize, F)>,
+    ) {
+        scores.clear();
+        scores.extend(self.actions.iter().enumerate().map(|(i, action)| {
+            let score = action.score(blackboard, current_action == Some(i));
+            (i, finite_or_zero(score))
+        }));
+    }
+
+    fn choose(
+        &self,
+        candidates: &[(usize, F)],
+        current_action: Option<usize>,
+        order: &mut Vec<usize>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> usize {
         match self.selection_method {
-            SelectionMethod::HighestScore => scores
-                .iter()
-                .enumerate()
-                .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(Ordering::Equal))
-                .map(|(idx, _)| idx)
-                .unwrap_or(0),
-            SelectionMethod::WeightedRandom => {
-                let rng = rng.expect("WeightedRandom requires RNG");
-                let mut positive = Vec::with_capacity(scores.len());
-                let mut total = F::zero();
-                for score in &scores {
-                    let val = if *score > F::zero() {
-                        *score
-                    } else {
-                        F::zero()
+            SelectionMethod::HighestScore => {
+                // Ties go to the current action, otherwise to the lowest index.
+                let mut best: Option<(usize, F)> = None;
+                for &(idx, score) in candidates {
+                    let better = match best {
+                        Some((_, best_score)) => {
+                            score > best_score
+                                || (score == best_score && current_action == Some(idx))
+                        }
+                        None => true,
                     };
-                    positive.push(val);
-                    total = total + val;
+                    if better {
+                        best = Some((idx, score));
+                    }
                 }
+                best.map(|(idx, _)| idx).unwrap_or(0)
+            }
+            SelectionMethod::WeightedRandom => {
+                let rng = rng.expect("WeightedRandom requires RNG");
+                let positive = |score: F| if score > F::zero() { score } else { F::zero() };
+                let total = candidates
+                    .iter()
+                    .fold(F::zero(), |acc, (_, score)| acc + positive(*score));
                 if total <= F::zero() {
-                    return 0;
+                    return candidates[0].0;
                 }
 
                 let roll_01 = (rng.next_u32() as f32) / ((u32::MAX as f32) + 1.0);
                 let roll = F::from_f32(roll_01) * total;
                 let mut cumulative = F::zero();
-                for (i, score) in positive.iter().enumerate() {
-                    cumulative = cumulative + *score;
+                for (idx, score) in candidates {
+                    cumulative = cumulative + positive(*score);
                     if roll < cumulative {
-                        return i;
+                        return *idx;
                     }
                 }
 
-                positive.len() - 1
+                candidates[candidates.len() - 1].0
             }
             SelectionMethod::TopN(n) => {
                 let rng = rng.expect("TopN requires RNG");
-                let mut indices: Vec<usize> = (0..scores.len()).collect();
-                indices.sort_by(|&a, &b| scores[b].partial_cmp(&scores[a]).unwrap_or(Ordering::Equal));
-                let n = n.max(1).min(indices.len());
-                indices[rng.next_u32() as usize % n]
+                order.clear();
+                order.extend(0..candidates.len());
+                // Index tie-break keeps the unstable (allocation-free) sort deterministic.
+                order.sort_unstable_by(|&a, &b| {
+                    candidates[b]
+                        .1
+                        .partial_cmp(&candidates[a].1)
+                        .unwrap_or(Ordering::Equal)
+                        .then(a.cmp(&b))
+                });
+                let n = n.max(1).min(order.len());
+                candidates[order[rng.next_u32() as usize % n]].0
             }
         }
     }
 
     pub fn score_all(
         &self,
-        blackboard: &Blackboard,
+        blackboard: &impl BlackboardStore,
         current_action: Option<usize>,
     ) -> Vec<(usize, F)> {
-        let mut out: Vec<(usize, F)> = self
-            .actions
-            .iter()
-            .enumerate()
-            .map(|(i, action)| (i, action.score(blackboard, current_action == Some(i))))
-            .collect();
+        let mut out = Vec::new();
+        self.score_into(blackboard, current_action, &mut out);
 
         out.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
         out
     }
+
+    /// `score_all` rescaled so the best score is 1.0, for display; all zeros when nothing
+    /// scores above zero.
+    pub fn score_all_normalized(
+        &self,
+        blackboard: &impl BlackboardStore,
+        current_action: Option<usize>,
+    ) -> Vec<(usize, F)> {
+        let mut out = self.score_all(blackboard, current_action);
+        let max = out.first().map(|(_, score)| *score).unwrap_or(F::zero());
+        for (_, score) in &mut out {
+            *score = if max > F::zero() {
+                (*score / max).clamp(F::zero(), F::one())
+            } else {
+                F::zero()
+            };
+        }
+        out
+    }
+}
+
+/// Maps NaN and infinite scores (e.g. from a misconfigured curve) to zero so they never win.
+fn finite_or_zero<F: Float>(score: F) -> F {
+    if score.is_finite() {
+        score
+    } else {
+        F::zero()
+    }
+}
+
+/// Wraps a [`Reasoner`], reusing each action's previous score until one of its consideration
+/// inputs is marked dirty.
+#[derive(Clone, Debug)]
+pub struct CachedReasoner<F: Float, A> {
+    reasoner: Reasoner<F, A>,
+    cache: Vec<Option<(bool, F)>>,
+    dirty: BTreeSet<u32>,
+    scores: Vec<(usize, F)>,
+}
+
+impl<F: Float, A> CachedReasoner<F, A> {
+    pub fn new(reasoner: Reasoner<F, A>) -> Self {
+        let cache = vec![None; reasoner.actions.len()];
+        Self {
+            reasoner,
+            cache,
+            dirty: BTreeSet::new(),
+            scores: Vec::new(),
+        }
+    }
+
+    pub fn reasoner(&self) -> &Reasoner<F, A> {
+        &self.reasoner
+    }
+
+    /// Marks a blackboard key as changed so actions reading it are re-scored.
+    pub fn mark_dirty(&mut self, key: u32) {
+        self.dirty.insert(key);
+    }
+
+    pub fn clear_dirty(&mut self) {
+        self.dirty.clear();
+    }
+
+    /// Drops every cached score.
+    pub fn invalidate(&mut self) {
+        self.cache.iter_mut().for_each(|entry| *entry = None);
+    }
+
+    pub fn select(
+        &mut self,
+        blackboard: &impl BlackboardStore,
+        current_action: Option<usize>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> usize {
+        if self.reasoner.actions.is_empty() {
+            return 0;
+        }
+
+        self.refresh_with(current_action, |c| c.evaluate(blackboard));
+        self.reasoner
+            .choose(&self.scores, current_action, &mut Vec::new(), rng)
+    }
+
+    /// Current per-action scores in action order, re-scoring only stale entries.
+    pub fn scores(
+        &mut self,
+        blackboard: &impl BlackboardStore,
+        current_action: Option<usize>,
+    ) -> &[(usize, F)] {
+        self.refresh_with(current_action, |c| c.evaluate(blackboard));
+        &self.scores
+    }
+
+    fn refresh_with(
+        &mut self,
+        current_action: Option<usize>,
+        mut evaluate: impl FnMut(&Consideration<F>) -> F,
+    ) {
+        self.cache.resize(self.reasoner.actions.len(), None);
+        self.scores.clear();
+        for (i, action) in self.reasoner.actions.iter().enumerate() {
+            let is_current = current_action == Some(i);
+            let stale = action
+                .considerations
+                .iter()
+                .any(|c| self.dirty.contains(&c.input_key));
+            let score = match self.cache[i] {
+                Some((was_current, score)) if !stale && was_current == is_current => score,
+                _ => {
+                    let score = finite_or_zero(action.score_with(is_current, &mut evaluate));
+                    self.cache[i] = Some((is_current, score));
+                    score
+                }
+            };
+            self.scores.push((i, score));
+        }
+    }
 }
 
 #[cfg(test)]
@@ -107,10 +312,11 @@ mod tests {
     use alloc::vec::Vec;
 
     use crate::blackboard::Blackboard;
+    use crate::observer::{ObserverEvent, RecordingObserver};
     use crate::utility::action::UtilityAction;
-    use crate::utility::consideration::Consideration;
+    use crate::utility::consideration::{ClampMode, Consideration};
     use crate::utility::curve::ResponseCurve;
-    use crate::utility::reasoner::{Reasoner, SelectionMethod};
+    use crate::utility::reasoner::{CachedReasoner, Reasoner, SelectionMethod};
     use rand_core::{Error, RngCore};
 
     struct SeqRng {
@@ -159,6 +365,10 @@ mod tests {
             weight: 1.0,
             input_min: 0.0,
             input_max: 1.0,
+            clamp_mode: ClampMode::Clamp,
+            invert: false,
+            bias: 0.0,
+            clamp_output: false,
         }
     }
 
@@ -258,4 +468,190 @@ mod tests {
         }
         assert!(high > low, "expected high score selected more often");
     }
+
+    #[test]
+    fn reasoner_select_above_threshold() {
+        let mut bb = Blackboard::new();
+        bb.set_float(1, 0.01);
+        bb.set_float(2, 0.02);
+        let mut reasoner = Reasoner {
+            actions: vec![
+                UtilityAction {
+                    action_id: 1u32,
+                    considerations: vec![linear(1)],
+                    weight: 1.0,
+                    momentum: 0.0,
+                },
+                UtilityAction {
+                    action_id: 2u32,
+                    considerations: vec![linear(2)],
+                    weight: 1.0,
+                    momentum: 0.0,
+                },
+            ],
+            selection_method: SelectionMethod::HighestScore,
+        };
+        assert_eq!(reasoner.select_above(&bb, None, None, 0.1), None);
+
+        bb.set_float(1, 0.5);
+        assert_eq!(reasoner.select_above(&bb, None, None, 0.1), Some(0));
+
+        reasoner.selection_method = SelectionMethod::TopN(2);
+        let mut rng = SeqRng::new(vec![1]);
+        assert_eq!(
+            reasoner.select_above(&bb, None, Some(&mut rng), 0.1),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn cached_reasoner_rescores_only_dirty_actions() {
+        let mut bb = Blackboard::new();
+        bb.set_float(1, 0.6);
+        bb.set_float(2, 0.4);
+        let mut cached = CachedReasoner::new(Reasoner {
+            actions: vec![
+                UtilityAction {
+                    action_id: 1u32,
+                    considerations: vec![linear(1)],
+                    weight: 1.0,
+                    momentum: 0.0,
+                },
+                UtilityAction {
+                    action_id: 2u32,
+                    considerations: vec![linear(2)],
+                    weight: 1.0,
+                    momentum: 0.0,
+                },
+            ],
+            selection_method: SelectionMethod::HighestScore,
+        });
+        assert_eq!(cached.select(&bb, None, None), 0);
+
+        let mut evaluated = Vec::new();
+        cached.refresh_with(None, |c| {
+            evaluated.push(c.input_key);
+            c.evaluate(&bb)
+        });
+        assert!(evaluated.is_empty());
+
+        bb.set_float(2, 0.9);
+        cached.mark_dirty(2);
+        cached.refresh_with(None, |c| {
+            evaluated.push(c.input_key);
+            c.evaluate(&bb)
+        });
+        assert_eq!(evaluated, vec![2]);
+
+        cached.clear_dirty();
+        assert_eq!(cached.select(&bb, None, None), 1);
+    }
+
+    #[test]
+    fn reasoner_highest_score_tie_break() {
+        let mut bb = Blackboard::new();
+        bb.set_float(1, 0.5);
+        let action = |action_id: u32| UtilityAction {
+            action_id,
+            considerations: vec![linear(1)],
+            weight: 1.0,
+            momentum: 0.0,
+        };
+        let reasoner = Reasoner {
+            actions: vec![action(1), action(2), action(3)],
+            selection_method: SelectionMethod::HighestScore,
+        };
+        assert_eq!(reasoner.select(&bb, None, None), 0);
+        assert_eq!(reasoner.select(&bb, Some(2), None), 2);
+    }
+
+    #[test]
+    fn reasoner_ignores_non_finite_scores() {
+        let mut bb = Blackboard::new();
+        bb.set_float(1, 0.5);
+        bb.set_float(2, 0.1);
+        let mut broken = linear(1);
+        broken.weight = f32::NAN;
+        let reasoner = Reasoner {
+            actions: vec![
+                UtilityAction {
+                    action_id: 1u32,
+                    considerations: vec![broken],
+                    weight: 1.0,
+                    momentum: 0.0,
+                },
+                UtilityAction {
+                    action_id: 2u32,
+                    considerations: vec![linear(2)],
+                    weight: 1.0,
+                    momentum: 0.0,
+                },
+            ],
+            selection_method: SelectionMethod::HighestScore,
+        };
+        assert!(reasoner.actions[0].score(&bb, false).is_nan());
+        assert_eq!(reasoner.select(&bb, None, None), 1);
+        assert_eq!(reasoner.score_all(&bb, None)[1], (0, 0.0));
+    }
+
+    #[test]
+    fn reasoner_score_all_normalized() {
+        let mut bb = Blackboard::new();
+        bb.set_float(1, 0.2);
+        bb.set_float(2, 0.8);
+        let reasoner = Reasoner {
+            actions: vec![
+                UtilityAction {
+                    action_id: 1u32,
+                    considerations: vec![linear(1)],
+                    weight: 2.0,
+                    momentum: 0.0,
+                },
+                UtilityAction {
+                    action_id: 2u32,
+                    considerations: vec![linear(2)],
+                    weight: 2.0,
+                    momentum: 0.0,
+                },
+            ],
+            selection_method: SelectionMethod::HighestScore,
+        };
+        let normalized = reasoner.score_all_normalized(&bb, None);
+        assert_eq!(normalized[0], (1, 1.0));
+        assert_eq!(normalized[1].0, 0);
+        assert!((normalized[1].1 - 0.25).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn reasoner_select_observed_reports_scores() {
+        let mut bb = Blackboard::new();
+        bb.set_float(1, 0.25);
+        bb.set_float(2, 0.75);
+        let reasoner = Reasoner {
+            actions: vec![
+                UtilityAction {
+                    action_id: 1u32,
+                    considerations: vec![linear(1)],
+                    weight: 1.0,
+                    momentum: 0.0,
+                },
+                UtilityAction {
+                    action_id: 2u32,
+                    considerations: vec![linear(2)],
+                    weight: 1.0,
+                    momentum: 0.0,
+                },
+            ],
+            selection_method: SelectionMethod::HighestScore,
+        };
+        let mut observer = RecordingObserver::default();
+        assert_eq!(reasoner.select_observed(&bb, None, None, &mut observer), 1);
+        assert_eq!(
+            observer.events,
+            vec![
+                ObserverEvent::UtilityScore(0, 0.25),
+                ObserverEvent::UtilityScore(1, 0.75),
+            ]
+        );
+    }
 }
@@ -0,0 +1,116 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use cogwise::utility::{
+    ClampMode, Consideration, Reasoner, ResponseCurve, SelectionMethod, UtilityAction,
+};
+use cogwise::{
+    ActionHandler, BehaviorNode, BehaviorTree, ConditionHandler, Context, NoOpObserver, SeededRng,
+    Status,
+};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|count| count.get())
+}
+
+struct SucceedAll;
+
+impl ActionHandler<u32> for SucceedAll {
+    fn execute(&mut self, _action: &u32, _ctx: &mut Context) -> Status {
+        Status::Success
+    }
+}
+
+impl ConditionHandler<u32> for SucceedAll {
+    fn check(&self, _condition: &u32, _ctx: &Context) -> bool {
+        true
+    }
+}
+
+fn actions(range: core::ops::Range<u32>) -> Vec<BehaviorNode<u32, u32>> {
+    range.map(BehaviorNode::Action).collect()
+}
+
+fn large_tree() -> BehaviorNode<u32, u32> {
+    let utility_action = |action_id: usize| UtilityAction {
+        action_id,
+        considerations: vec![Consideration {
+            input_key: action_id as u32,
+            curve: ResponseCurve::Linear {
+                slope: 1.0,
+                offset: 0.0,
+            },
+            weight: 1.0,
+            input_min: 0.0,
+            input_max: 50.0,
+            clamp_mode: ClampMode::Clamp,
+            invert: false,
+            bias: 0.0,
+            clamp_output: false,
+        }],
+        weight: 1.0,
+        momentum: 0.1,
+    };
+
+    BehaviorNode::Sequence(vec![
+        BehaviorNode::ShuffledSelector {
+            children: actions(0..50),
+            seed: Some(3),
+        },
+        BehaviorNode::BlackboardWeightedSelector {
+            children: actions(50..100),
+            weight_keys: (0..50).collect(),
+            seed: None,
+        },
+        BehaviorNode::Reasoner {
+            reasoner: Reasoner {
+                actions: (0..50).map(utility_action).collect(),
+                selection_method: SelectionMethod::TopN(3),
+            },
+            children: actions(100..150),
+        },
+        BehaviorNode::Sequence(actions(150..495)),
+    ])
+}
+
+#[test]
+fn steady_state_ticks_do_not_allocate() {
+    let mut tree = BehaviorTree::with_rng(large_tree(), SeededRng::new(7));
+    assert_eq!(tree.node_count(), 500);
+    for key in 0..50 {
+        tree.blackboard_mut().set_float(key, key as f32);
+    }
+    let mut handler = SucceedAll;
+    let mut observer = NoOpObserver;
+
+    for _ in 0..10 {
+        tree.tick(&mut handler, &SucceedAll, &mut observer);
+    }
+
+    let before = allocations();
+    for _ in 0..100 {
+        let outcome = tree.tick(&mut handler, &SucceedAll, &mut observer);
+        assert_eq!(outcome.status, Status::Success);
+    }
+    assert_eq!(allocations() - before, 0);
+}
@@ -1,5 +1,8 @@
 use alloc::collections::BTreeMap;
 
+use cogwise::utility::{
+    ClampMode, Consideration, Reasoner, ResponseCurve, SelectionMethod, UtilityAction,
+};
 use cogwise::{
     preset, ActionHandler, BehaviorNode, BehaviorTree, ConditionHandler, Context, NoOpObserver,
     Status,
@@ -81,3 +84,47 @@ fn integration_utility_selector_picks_best() {
     assert_eq!(status, Status::Success);
     assert_eq!(actions.calls, vec![20]);
 }
+
+#[test]
+fn integration_reasoner_node_follows_blackboard() {
+    let action = |action_id: usize, input_key: u32| UtilityAction {
+        action_id,
+        considerations: vec![Consideration {
+            input_key,
+            curve: ResponseCurve::Linear {
+                slope: 1.0,
+                offset: 0.0,
+            },
+            weight: 1.0,
+            input_min: 0.0,
+            input_max: 1.0,
+            clamp_mode: ClampMode::Clamp,
+            invert: false,
+            bias: 0.0,
+            clamp_output: false,
+        }],
+        weight: 1.0,
+        momentum: 0.0,
+    };
+    let root = BehaviorNode::Reasoner {
+        reasoner: Reasoner {
+            actions: vec![action(0, 1), action(1, 2)],
+            selection_method: SelectionMethod::HighestScore,
+        },
+        children: vec![BehaviorNode::Action(10), BehaviorNode::Action(20)],
+    };
+    let mut tree = BehaviorTree::new(root);
+    tree.blackboard_mut().set_float(1, 0.8);
+    tree.blackboard_mut().set_float(2, 0.3);
+
+    let mut actions = RecordingActionHandler::default();
+    let conditions = MapConditionHandler::default();
+    let mut observer = NoOpObserver;
+    let status = tree.tick(&mut actions, &conditions, &mut observer);
+    assert_eq!(status, Status::Success);
+
+    tree.blackboard_mut().set_float(1, 0.1);
+    let status = tree.tick(&mut actions, &conditions, &mut observer);
+    assert_eq!(status, Status::Success);
+    assert_eq!(actions.calls, vec![10, 20]);
+}